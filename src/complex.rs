@@ -0,0 +1,333 @@
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use crate::traits::{Zero, One, Ring, Field, Real, ComplexField};
+
+/// A complex number with real part `re` and imaginary part `im`.
+/// 
+/// It implements [Ring] whenever its parts do, and [Field] when they are [Real],
+/// so it can be used as the element type of a [Vector][crate::vector_impl::Vector] or a
+/// [Matrix][crate::matrix_impl::Matrix].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::complex::Complex;
+/// 
+/// let lhs = Complex::new(1.0, 2.0);
+/// let rhs = Complex::new(4.0, -2.0);
+/// 
+/// assert_eq!(lhs + rhs, Complex::new(5.0, 0.0));
+/// assert_eq!(lhs * rhs, Complex::new(8.0, 6.0));
+/// assert_eq!(lhs * rhs / rhs, lhs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re : T,
+
+    /// The imaginary part.
+    pub im : T
+}
+
+impl<T> Complex<T> {
+    /// Constructs a complex number from its real and imaginary parts.
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex {
+            re,
+            im
+        }
+    }
+
+    /// Returns the complex conjugate `re - im i`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let value = Complex::new(1.0, 2.0);
+    /// 
+    /// assert_eq!(value.conjugate(), Complex::new(1.0, -2.0));
+    /// assert_eq!(value * value.conjugate(), Complex::new(5.0, 0.0));
+    /// ```
+    pub fn conjugate(self) -> Complex<T>
+    where
+        T: Neg<Output = T>
+    {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Real
+{
+    /// Returns the magnitude `|z|`, scaled to avoid
+    /// overflow and underflow in the intermediate square.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// assert_eq!(Complex::new(3.0, -4.0).norm(), 5.0);
+    /// ```
+    pub fn norm(self) -> T {
+        let re = self.re.abs();
+        let im = self.im.abs();
+
+        let (large, small) = if re >= im { (re, im) } else { (im, re) };
+
+        if large.is_zero() {
+            return T::zero()
+        }
+
+        let ratio = small / large;
+        large * (T::one() + ratio * ratio).sqrt()
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: Zero
+{
+    fn from(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+}
+
+impl<T> Zero for Complex<T>
+where
+    T: Ring
+{
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T> One for Complex<T>
+where
+    T: Ring
+{
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Field for Complex<T>
+where
+    T: Real
+{}
+
+impl<T> ComplexField for Complex<T>
+where
+    T: Real
+{
+    type Real = T;
+
+    fn conjugate(self) -> Self {
+        Complex::conjugate(self)
+    }
+
+    fn real(self) -> Self::Real {
+        self.re
+    }
+
+    fn imaginary(self) -> Self::Real {
+        self.im
+    }
+
+    fn modulus(self) -> Self::Real {
+        self.norm()
+    }
+
+    fn from_real(val: Self::Real) -> Self {
+        Complex::from(val)
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Ring
+{
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Ring
+{
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Ring
+{
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Self::Output {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Real
+{
+    type Output = Complex<T>;
+
+    // Smith's algorithm, which divides through by the larger part of 'rhs'
+    // instead of forming '|rhs|²', so it does not overflow or underflow early.
+    fn div(self, rhs: Complex<T>) -> Self::Output {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let ratio = rhs.im / rhs.re;
+            let denominator = rhs.re + rhs.im * ratio;
+
+            Complex::new(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator
+            )
+        } else {
+            let ratio = rhs.re / rhs.im;
+            let denominator = rhs.re * ratio + rhs.im;
+
+            Complex::new(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator
+            )
+        }
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output = T>
+{
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T> AddAssign for Complex<T>
+where
+    T: Ring
+{
+    fn add_assign(&mut self, rhs: Complex<T>) {
+        *self = *self + rhs
+    }
+}
+
+impl<T> SubAssign for Complex<T>
+where
+    T: Ring
+{
+    fn sub_assign(&mut self, rhs: Complex<T>) {
+        *self = *self - rhs
+    }
+}
+
+impl<T> MulAssign for Complex<T>
+where
+    T: Ring
+{
+    fn mul_assign(&mut self, rhs: Complex<T>) {
+        *self = *self * rhs
+    }
+}
+
+impl<T> DivAssign for Complex<T>
+where
+    T: Real
+{
+    fn div_assign(&mut self, rhs: Complex<T>) {
+        *self = *self / rhs
+    }
+}
+
+// With the 'num-complex' feature enabled, num_complex::Complex can also be used
+// as an element type for the Hermitian operations, and converted to and from [Complex].
+#[cfg(feature = "num-complex")]
+impl<T> Zero for num_complex::Complex<T>
+where
+    num_complex::Complex<T>: num_traits::Zero
+{
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        num_traits::Zero::is_zero(self)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T> One for num_complex::Complex<T>
+where
+    num_complex::Complex<T>: num_traits::One
+{
+    fn one() -> Self {
+        num_traits::One::one()
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T> Field for num_complex::Complex<T>
+where
+    T: Real + num_traits::NumAssign
+{}
+
+#[cfg(feature = "num-complex")]
+impl<T> ComplexField for num_complex::Complex<T>
+where
+    T: Real + num_traits::NumAssign
+{
+    type Real = T;
+
+    fn conjugate(self) -> Self {
+        num_complex::Complex::new(self.re, -self.im)
+    }
+
+    fn real(self) -> Self::Real {
+        self.re
+    }
+
+    fn imaginary(self) -> Self::Real {
+        self.im
+    }
+
+    fn modulus(self) -> Self::Real {
+        Complex::from(self).norm()
+    }
+
+    fn from_real(val: Self::Real) -> Self {
+        num_complex::Complex::new(val, <T as Zero>::zero())
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T> From<num_complex::Complex<T>> for Complex<T> {
+    fn from(value: num_complex::Complex<T>) -> Self {
+        Complex::new(value.re, value.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<T> From<Complex<T>> for num_complex::Complex<T> {
+    fn from(value: Complex<T>) -> Self {
+        num_complex::Complex::new(value.re, value.im)
+    }
+}
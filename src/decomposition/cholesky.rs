@@ -0,0 +1,484 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError, LinalgError};
+use crate::traits::Real;
+
+/// The Cholesky decomposition of a symmetric positive definite matrix,
+/// created by [Matrix::cholesky].
+/// 
+/// The decomposition satisfies `A = L * Lᵀ`, where `L` is lower triangular
+/// with a positive diagonal.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[4.0, 2.0],
+///                      [2.0, 5.0]];
+/// 
+/// let cholesky = matrix.cholesky().unwrap();
+/// 
+/// assert_eq!(cholesky.l(), matrix![[2.0, 0.0],
+///                                  [1.0, 2.0]]);
+/// assert_eq!(cholesky.determinant(), 16.0);
+/// assert_eq!(cholesky.solve(&vector![6.0, 7.0]), Ok(vector![1.0, 1.0]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cholesky<T> {
+    l : Matrix<T>
+}
+
+/// The LDLᵀ decomposition of a symmetric positive semi-definite matrix,
+/// created by [Matrix::ldl].
+/// 
+/// The decomposition satisfies `A = L * D * Lᵀ`, where `L` is unit lower
+/// triangular and `D` is diagonal with non-negative entries. Unlike
+/// [Cholesky], it takes no square roots and accepts singular matrices.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[1.0, 1.0],
+///                      [1.0, 1.0]];
+/// 
+/// let ldl = matrix.ldl().unwrap();
+/// 
+/// assert_eq!(ldl.l(), matrix![[1.0, 0.0],
+///                             [1.0, 1.0]]);
+/// assert_eq!(ldl.d(), vector![1.0, 0.0]);
+/// assert!(ldl.is_singular());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LDL<T> {
+    // L (below the diagonal, with an implied unit diagonal)
+    // and D (on the diagonal) packed together.
+    ld : Matrix<T>,
+    tolerance : T
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the Cholesky decomposition of a symmetric positive definite matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let covariance = matrix![[ 4.0, 12.0, -16.0],
+    ///                          [12.0, 37.0, -43.0],
+    ///                          [-16.0, -43.0, 98.0]];
+    /// 
+    /// let cholesky = covariance.cholesky().unwrap();
+    /// 
+    /// assert_eq!(cholesky.l(), matrix![[ 2.0, 0.0, 0.0],
+    ///                                  [ 6.0, 1.0, 0.0],
+    ///                                  [-8.0, 5.0, 3.0]]);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square,
+    /// [LinalgError::NotSymmetric] if it is not symmetric, or
+    /// [LinalgError::NotPositiveDefinite] if it is not positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<T>, LinalgError> {
+        let tolerance = symmetric_tolerance(self)?;
+        let size = self.rows();
+
+        let mut l = Matrix::zeros(size, size);
+
+        for col in 0..size {
+            let mut diagonal = self[(col, col)];
+            for val in &l.row(col)[..col] {
+                diagonal -= *val * *val
+            }
+
+            if diagonal <= tolerance {
+                return Err(LinalgError::NotPositiveDefinite)
+            }
+
+            let diagonal = diagonal.sqrt();
+            l[(col, col)] = diagonal;
+
+            for row in col + 1..size {
+                let mut val = self[(row, col)];
+                for (lhs, rhs) in l.row(row)[..col].iter().zip(&l.row(col)[..col]) {
+                    val -= *lhs * *rhs
+                }
+
+                l[(row, col)] = val / diagonal
+            }
+        }
+
+        Ok(Cholesky {
+            l
+        })
+    }
+
+    /// Computes the LDLᵀ decomposition of a symmetric positive semi-definite matrix.
+    /// 
+    /// Diagonal entries of `D` at or below `ε * n * max|A|` are treated as zero,
+    /// in which case the rest of their column must vanish as well.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[4.0, 2.0],
+    ///                      [2.0, 5.0]];
+    /// 
+    /// let ldl = matrix.ldl().unwrap();
+    /// 
+    /// assert_eq!(ldl.l(), matrix![[1.0, 0.0],
+    ///                             [0.5, 1.0]]);
+    /// assert_eq!(ldl.d(), vector![4.0, 4.0]);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square,
+    /// [LinalgError::NotSymmetric] if it is not symmetric, or
+    /// [LinalgError::NotPositiveDefinite] if it is not positive semi-definite.
+    pub fn ldl(&self) -> Result<LDL<T>, LinalgError> {
+        let tolerance = symmetric_tolerance(self)?;
+        let size = self.rows();
+
+        let mut ld = Matrix::zeros(size, size);
+        let mut scaled = vec![T::zero(); size];
+
+        for col in 0..size {
+            // Row 'col' of L scaled by D, reused by every row below.
+            for (idx, val) in scaled.iter_mut().enumerate().take(col) {
+                *val = ld[(col, idx)] * ld[(idx, idx)]
+            }
+
+            let mut diagonal = self[(col, col)];
+            for (lhs, rhs) in ld.row(col)[..col].iter().zip(&scaled) {
+                diagonal -= *lhs * *rhs
+            }
+
+            if diagonal < -tolerance {
+                return Err(LinalgError::NotPositiveDefinite)
+            }
+
+            let vanishing = diagonal <= tolerance;
+            ld[(col, col)] = if vanishing { T::zero() } else { diagonal };
+
+            for row in col + 1..size {
+                let mut val = self[(row, col)];
+                for (lhs, rhs) in ld.row(row)[..col].iter().zip(&scaled) {
+                    val -= *lhs * *rhs
+                }
+
+                if vanishing {
+                    // A zero pivot with a non-zero column below it
+                    // means the matrix is indefinite.
+                    if val.abs() > tolerance {
+                        return Err(LinalgError::NotPositiveDefinite)
+                    }
+                } else {
+                    ld[(row, col)] = val / diagonal
+                }
+            }
+        }
+
+        Ok(LDL {
+            ld,
+            tolerance
+        })
+    }
+}
+
+// Checks that the matrix is square and symmetric, returning the tolerance
+// used for the comparison, which is 'ε * n * max|A|'.
+pub(crate) fn symmetric_tolerance<T>(matrix: &Matrix<T>) -> Result<T, LinalgError>
+where
+    T: Real
+{
+    if matrix.rows() != matrix.cols() {
+        return Err(LinalgError::NotSquare(matrix.rows(), matrix.cols()))
+    }
+
+    let size = matrix.rows();
+
+    let mut max_abs = T::zero();
+    for val in matrix.iter() {
+        if val.abs() > max_abs {
+            max_abs = val.abs()
+        }
+    }
+
+    let tolerance = T::epsilon() * T::from_f64(size as f64) * max_abs;
+
+    for row in 0..size {
+        for col in row + 1..size {
+            if (matrix[(row, col)] - matrix[(col, row)]).abs() > tolerance {
+                return Err(LinalgError::NotSymmetric)
+            }
+        }
+    }
+
+    Ok(tolerance)
+}
+
+impl<T> Cholesky<T>
+where
+    T: Real
+{
+    /// Returns the lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T> {
+        self.l.clone()
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    /// 
+    /// For large matrices the determinant easily overflows or underflows,
+    /// in which case [log_determinant][Cholesky::log_determinant] should be used.
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+
+        for idx in 0..self.l.rows() {
+            det *= self.l[(idx, idx)]
+        }
+
+        det * det
+    }
+
+    /// Returns the natural logarithm of the determinant of the decomposed matrix,
+    /// which is always defined since the determinant is positive.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = Matrix::identity(400) * 10.0;
+    /// 
+    /// let cholesky = matrix.cholesky().unwrap();
+    /// 
+    /// assert!(cholesky.determinant().is_infinite());
+    /// assert!((cholesky.log_determinant() - 400.0 * 10f64.ln()).abs() < 1e-9);
+    /// ```
+    pub fn log_determinant(&self) -> T {
+        let mut log_det = T::zero();
+
+        for idx in 0..self.l.rows() {
+            log_det += self.l[(idx, idx)].ln()
+        }
+
+        log_det + log_det
+    }
+
+    /// Solves `A * x = b` for `x`.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b`
+    /// is not equal to the size of the matrix.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let size = self.l.rows();
+
+        if rhs.len() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Vector(rhs.len())).into())
+        }
+
+        // Forward substitution: L * y = b.
+        let mut params: Vec<T> = Vec::with_capacity(size);
+        for row_idx in 0..size {
+            let mut param = rhs[row_idx];
+
+            for (factor, solved) in self.l.row(row_idx)[..row_idx].iter().zip(&params) {
+                param -= *factor * *solved
+            }
+
+            params.push(param / self.l[(row_idx, row_idx)])
+        }
+
+        // Back substitution: Lᵀ * x = y.
+        for row_idx in (0..size).rev() {
+            let mut param = params[row_idx];
+
+            for (col_idx, solved) in params.iter().enumerate().skip(row_idx + 1) {
+                param -= self.l[(col_idx, row_idx)] * *solved
+            }
+
+            params[row_idx] = param / self.l[(row_idx, row_idx)]
+        }
+
+        Ok(Vector::from(params))
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B`
+    /// as a separate right hand side.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the number of rows in `B`
+    /// is not equal to the size of the matrix.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        let size = self.l.rows();
+        let cols = rhs.cols();
+
+        if rhs.rows() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Matrix(rhs.rows(), cols)).into())
+        }
+
+        let mut params: Vec<T> = Vec::with_capacity(size * cols);
+        for row_idx in 0..size {
+            params.extend_from_slice(rhs.row(row_idx));
+
+            for col_idx in 0..row_idx {
+                let factor = self.l[(row_idx, col_idx)];
+
+                for idx in 0..cols {
+                    let solved = params[col_idx * cols + idx];
+                    params[row_idx * cols + idx] -= factor * solved
+                }
+            }
+
+            let pivot = self.l[(row_idx, row_idx)];
+            for idx in 0..cols {
+                params[row_idx * cols + idx] /= pivot
+            }
+        }
+
+        for row_idx in (0..size).rev() {
+            for col_idx in row_idx + 1..size {
+                let factor = self.l[(col_idx, row_idx)];
+
+                for idx in 0..cols {
+                    let solved = params[col_idx * cols + idx];
+                    params[row_idx * cols + idx] -= factor * solved
+                }
+            }
+
+            let pivot = self.l[(row_idx, row_idx)];
+            for idx in 0..cols {
+                params[row_idx * cols + idx] /= pivot
+            }
+        }
+
+        Ok(Matrix::from_parts(size, cols, params))
+    }
+
+    /// Returns the inverse of the decomposed matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[4.0, 2.0],
+    ///                      [2.0, 5.0]];
+    /// 
+    /// let inverse = matrix.cholesky().unwrap().inverse();
+    /// 
+    /// assert_eq!(inverse, matrix![[ 0.3125, -0.125],
+    ///                             [-0.125,   0.25]]);
+    /// ```
+    pub fn inverse(&self) -> Matrix<T> {
+        let size = self.l.rows();
+
+        self.solve_matrix(&Matrix::identity(size))
+            .expect("the identity matrix has the same size as the decomposition")
+    }
+}
+
+impl<T> LDL<T>
+where
+    T: Real
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T> {
+        let size = self.ld.rows();
+
+        Matrix::from_fn(size, size, |row, col| {
+            if row > col {
+                self.ld[(row, col)]
+            } else if row == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the diagonal of `D`.
+    pub fn d(&self) -> Vector<T> {
+        (0..self.ld.rows())
+            .map(|idx| self.ld[(idx, idx)])
+            .collect()
+    }
+
+    /// Returns true if an entry of `D` is zero, which
+    /// happens exactly when the matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        let size = self.ld.rows();
+
+        (0..size).any(|idx| self.ld[(idx, idx)] <= self.tolerance)
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+
+        for idx in 0..self.ld.rows() {
+            det *= self.ld[(idx, idx)]
+        }
+
+        det
+    }
+
+    /// Solves `A * x = b` for `x`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[4.0, 2.0],
+    ///                      [2.0, 5.0]];
+    /// 
+    /// assert_eq!(matrix.ldl().unwrap().solve(&vector![6.0, 7.0]), Ok(vector![1.0, 1.0]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let size = self.ld.rows();
+
+        if rhs.len() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Vector(rhs.len())).into())
+        }
+
+        if self.is_singular() {
+            return Err(LinalgError::Singular)
+        }
+
+        // Forward substitution: L * y = b.
+        let mut params: Vec<T> = Vec::with_capacity(size);
+        for row_idx in 0..size {
+            let mut param = rhs[row_idx];
+
+            for (factor, solved) in self.ld.row(row_idx)[..row_idx].iter().zip(&params) {
+                param -= *factor * *solved
+            }
+
+            params.push(param)
+        }
+
+        // Diagonal scaling: D * z = y.
+        for (idx, param) in params.iter_mut().enumerate() {
+            *param /= self.ld[(idx, idx)]
+        }
+
+        // Back substitution: Lᵀ * x = z.
+        for row_idx in (0..size).rev() {
+            let mut param = params[row_idx];
+
+            for (col_idx, solved) in params.iter().enumerate().skip(row_idx + 1) {
+                param -= self.ld[(col_idx, row_idx)] * *solved
+            }
+
+            params[row_idx] = param
+        }
+
+        Ok(Vector::from(params))
+    }
+}
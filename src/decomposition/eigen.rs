@@ -0,0 +1,591 @@
+use std::ops::Range;
+
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::complex::Complex;
+use crate::error::LinalgError;
+use crate::traits::{Zero, Real};
+
+// The number of Francis steps allowed for each eigenvalue, or complex pair,
+// to split off. Exceptional shifts are used on every tenth step to break
+// the cycles that the standard shifts occasionally fall into.
+const MAX_ITERATIONS: usize = 30;
+
+/// The eigenvalues and eigenvectors of a general square matrix,
+/// created by [Matrix::eigen].
+/// 
+/// Complex eigenvalues of a real matrix come in conjugate pairs, which are
+/// stored next to each other with the positive imaginary part first. Each
+/// eigenvector has unit length and is scaled so that its component of
+/// largest magnitude is real and positive, which makes the eigenvectors
+/// of real eigenvalues real.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::complex::Complex;
+/// 
+/// // A rotation by a quarter turn has eigenvalues ±i.
+/// let matrix: Matrix<f64> = matrix![[0.0, -1.0],
+///                                   [1.0,  0.0]];
+/// 
+/// let eigen = matrix.eigen().unwrap();
+/// 
+/// for (idx, value) in eigen.eigenvalues().iter().enumerate() {
+///     let vector = eigen.eigenvectors().col(idx);
+/// 
+///     for row in 0..2 {
+///         let mut product = Complex::new(0.0, 0.0);
+///         for col in 0..2 {
+///             product += Complex::from(matrix[(row, col)]) * vector[col]
+///         }
+/// 
+///         assert!((product - *value * vector[row]).norm() < 1e-12)
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Eigen<T> {
+    eigenvalues : Vector<Complex<T>>,
+    eigenvectors : Matrix<Complex<T>>
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the possibly complex eigenvalues of a square matrix.
+    /// 
+    /// The matrix is reduced to upper Hessenberg form by Householder
+    /// reflections, and then to real Schur form by the Francis double-shift
+    /// QR algorithm, whose 1x1 and 2x2 diagonal blocks hold the eigenvalues.
+    /// The eigenvalues follow the order of those blocks, with each complex
+    /// conjugate pair stored next to each other, positive imaginary part first.
+    /// 
+    /// For symmetric matrices, [symmetric_eigen][Matrix::symmetric_eigen]
+    /// is more accurate and returns real, sorted eigenvalues.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[ 0.0,  1.0],
+    ///                                   [-2.0, -2.0]];
+    /// 
+    /// let eigenvalues = matrix.eigenvalues().unwrap();
+    /// 
+    /// // The eigenvalues -1 ± i have negative real parts, so x' = A * x is stable.
+    /// assert!(eigenvalues.iter().all(|value| value.re < 0.0));
+    /// assert!((eigenvalues[0].re + 1.0).abs() < 1e-12);
+    /// assert!((eigenvalues[0].im - 1.0).abs() < 1e-12);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square, or
+    /// [LinalgError::NoConvergence] if an eigenvalue fails to converge.
+    pub fn eigenvalues(&self) -> Result<Vector<Complex<T>>, LinalgError> {
+        let (schur, _) = real_schur(self, false)?;
+
+        Ok(blocks(&schur)
+            .into_iter()
+            .flat_map(|block| block_values(&schur, block))
+            .collect())
+    }
+
+    /// Computes the possibly complex eigenvalues and eigenvectors of a square matrix.
+    /// 
+    /// The eigenvalues are found as in [eigenvalues][Matrix::eigenvalues], and the
+    /// eigenvectors by back substitution on the real Schur form.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[2.0, 1.0],
+    ///                                   [0.0, 3.0]];
+    /// 
+    /// let eigen = matrix.eigen().unwrap();
+    /// let root_half = 0.5f64.sqrt();
+    /// 
+    /// assert_eq!(eigen.eigenvalues()[1].re, 3.0);
+    /// assert!((eigen.eigenvectors()[(0, 1)].re - root_half).abs() < 1e-12);
+    /// assert!((eigen.eigenvectors()[(1, 1)].re - root_half).abs() < 1e-12);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square, or
+    /// [LinalgError::NoConvergence] if an eigenvalue fails to converge.
+    pub fn eigen(&self) -> Result<Eigen<T>, LinalgError> {
+        let (schur, vectors) = real_schur(self, true)?;
+        let size = self.rows();
+
+        let mut max_abs = T::zero();
+        for val in schur.iter() {
+            if val.abs() > max_abs {
+                max_abs = val.abs()
+            }
+        }
+
+        // Zero pivots met during back substitution, from repeated eigenvalues,
+        // are replaced by this to keep the eigenvectors finite.
+        let small = if max_abs.is_zero() { T::epsilon() } else { T::epsilon() * max_abs };
+
+        let blocks = blocks(&schur);
+        let mut eigenvalues = Vec::with_capacity(size);
+        let mut columns: Vec<Vec<Complex<T>>> = Vec::with_capacity(size);
+
+        for (idx, block) in blocks.iter().enumerate() {
+            let values = block_values(&schur, block.clone());
+            let value = values[0];
+
+            // An eigenvector of the block itself, padded with zeros below.
+            let mut params = vec![Complex::from(T::zero()); size];
+            if block.len() == 1 {
+                params[block.start] = Complex::from(T::one())
+            } else {
+                params[block.start] = Complex::from(schur[(block.start, block.start + 1)]);
+                params[block.start + 1] = value - Complex::from(schur[(block.start, block.start)]);
+            }
+
+            back_substitute(&schur, &blocks[..idx], value, small, &mut params);
+
+            let column = normalize(&vectors, &params);
+
+            if block.len() == 2 {
+                eigenvalues.push(value);
+                eigenvalues.push(values[1]);
+                columns.push(column.clone());
+                columns.push(column.iter().map(|val| Complex::new(val.re, -val.im)).collect())
+            } else {
+                eigenvalues.push(value);
+                columns.push(column)
+            }
+        }
+
+        Ok(Eigen {
+            eigenvalues : Vector::from(eigenvalues),
+            eigenvectors : Matrix::from_fn(size, size, |row, col| columns[col][row])
+        })
+    }
+}
+
+impl<T> Eigen<T> {
+    /// Returns the eigenvalues.
+    pub fn eigenvalues(&self) -> &Vector<Complex<T>> {
+        &self.eigenvalues
+    }
+
+    /// Returns the eigenvectors as columns, where column `i`
+    /// belongs to the eigenvalue at index `i`.
+    pub fn eigenvectors(&self) -> &Matrix<Complex<T>> {
+        &self.eigenvectors
+    }
+}
+
+// Reduces the matrix to real Schur form 'T', returning it along with the
+// orthogonal 'Z' satisfying 'A = Z * T * Zᵀ' when 'accumulate' is set.
+fn real_schur<T>(matrix: &Matrix<T>, accumulate: bool) -> Result<(Matrix<T>, Option<Matrix<T>>), LinalgError>
+where
+    T: Real
+{
+    if matrix.rows() != matrix.cols() {
+        return Err(LinalgError::NotSquare(matrix.rows(), matrix.cols()))
+    }
+
+    let size = matrix.rows();
+    let mut schur = matrix.clone();
+    let mut vectors = if accumulate { Some(Matrix::identity(size)) } else { None };
+
+    // Hessenberg reduction, zeroing each column below its subdiagonal.
+    for col in 0..size.saturating_sub(2) {
+        let column: Vec<T> = (col + 1..size).map(|row| schur[(row, col)]).collect();
+
+        if let Some((reflector, factor, beta)) = householder(&column) {
+            reflect_rows(&mut schur, &reflector, factor, col + 1, col..size);
+            reflect_cols(&mut schur, &reflector, factor, col + 1, 0..size);
+            if let Some(vectors) = vectors.as_mut() {
+                reflect_cols(vectors, &reflector, factor, col + 1, 0..size)
+            }
+
+            schur[(col + 1, col)] = beta;
+            for row in col + 2..size {
+                schur[(row, col)] = T::zero()
+            }
+        }
+    }
+
+    let mut norm = T::zero();
+    for val in schur.iter() {
+        norm += val.abs()
+    }
+
+    // The rows from 'active' onwards have converged.
+    let mut active = size;
+    let mut iterations = 0;
+
+    while active > 0 {
+        let end = active - 1;
+
+        // Find the start of the unreduced block ending at 'end',
+        // splitting at the first negligible subdiagonal element.
+        let mut start = end;
+        while start > 0 {
+            let mut scale = schur[(start - 1, start - 1)].abs() + schur[(start, start)].abs();
+            if scale.is_zero() {
+                scale = norm
+            }
+
+            if schur[(start, start - 1)].abs() <= T::epsilon() * scale {
+                schur[(start, start - 1)] = T::zero();
+                break
+            }
+
+            start -= 1
+        }
+
+        if start == end {
+            active -= 1;
+            iterations = 0;
+            continue
+        }
+
+        if start + 1 == end {
+            split_block(&mut schur, vectors.as_mut(), start);
+            active -= 2;
+            iterations = 0;
+            continue
+        }
+
+        if iterations == MAX_ITERATIONS {
+            return Err(LinalgError::NoConvergence)
+        }
+        iterations += 1;
+
+        // The shifts are the eigenvalues of the trailing 2x2 block,
+        // given through their sum and product.
+        let (trace, determinant) = if iterations % 10 == 0 {
+            let scale = schur[(end, end - 1)].abs() + schur[(end - 1, end - 2)].abs();
+            let diagonal = schur[(end, end)] + T::from_f64(0.75) * scale;
+
+            (diagonal + diagonal, diagonal * diagonal + T::from_f64(0.4375) * scale * scale)
+        } else {
+            let upper_left = schur[(end - 1, end - 1)];
+            let lower_right = schur[(end, end)];
+
+            (upper_left + lower_right, upper_left * lower_right - schur[(end - 1, end)] * schur[(end, end - 1)])
+        };
+
+        // The first column of (H - σ₁I) * (H - σ₂I), which starts the bulge.
+        let h00 = schur[(start, start)];
+        let h10 = schur[(start + 1, start)];
+        let h11 = schur[(start + 1, start + 1)];
+
+        let mut x = h00 * h00 + schur[(start, start + 1)] * h10 - trace * h00 + determinant;
+        let mut y = h10 * (h00 + h11 - trace);
+        let mut z = h10 * schur[(start + 2, start + 1)];
+
+        // Chase the bulge down the subdiagonal.
+        for step in start..end - 1 {
+            if let Some((reflector, factor, _)) = householder(&[x, y, z]) {
+                let first_col = if step > start { step - 1 } else { start };
+                let last_row = (step + 3).min(end);
+
+                reflect_rows(&mut schur, &reflector, factor, step, first_col..size);
+                reflect_cols(&mut schur, &reflector, factor, step, 0..last_row + 1);
+                if let Some(vectors) = vectors.as_mut() {
+                    reflect_cols(vectors, &reflector, factor, step, 0..size)
+                }
+
+                if step > start {
+                    schur[(step + 1, step - 1)] = T::zero();
+                    schur[(step + 2, step - 1)] = T::zero();
+                }
+            }
+
+            x = schur[(step + 1, step)];
+            y = schur[(step + 2, step)];
+            if step + 3 <= end {
+                z = schur[(step + 3, step)]
+            }
+        }
+
+        if let Some((reflector, factor, _)) = householder(&[x, y]) {
+            reflect_rows(&mut schur, &reflector, factor, end - 1, end - 2..size);
+            reflect_cols(&mut schur, &reflector, factor, end - 1, 0..end + 1);
+            if let Some(vectors) = vectors.as_mut() {
+                reflect_cols(vectors, &reflector, factor, end - 1, 0..size)
+            }
+
+            schur[(end, end - 2)] = T::zero();
+        }
+    }
+
+    Ok((schur, vectors))
+}
+
+// Computes the reflector 'H = I - factor * v * vᵀ' with 'v[0] = 1' mapping 'x'
+// onto 'beta * e₁', returning '(v, factor, beta)', or None if 'x' already is.
+fn householder<T>(x: &[T]) -> Option<(Vec<T>, T, T)>
+where
+    T: Real
+{
+    let head = x[0];
+    let mut tail_norm = T::zero();
+    for val in &x[1..] {
+        tail_norm += *val * *val
+    }
+
+    if tail_norm.is_zero() {
+        return None
+    }
+
+    let norm = (head * head + tail_norm).sqrt();
+    let beta = if head >= T::zero() { -norm } else { norm };
+    let scale = T::one() / (head - beta);
+
+    let mut reflector = Vec::with_capacity(x.len());
+    reflector.push(T::one());
+    reflector.extend(x[1..].iter().map(|val| *val * scale));
+
+    Some((reflector, (beta - head) / beta, beta))
+}
+
+// Applies the reflector from the left to the rows starting at 'first_row',
+// within the given columns.
+fn reflect_rows<T>(matrix: &mut Matrix<T>, reflector: &[T], factor: T, first_row: usize, cols: Range<usize>)
+where
+    T: Real
+{
+    for col in cols {
+        let mut dot = T::zero();
+        for (idx, val) in reflector.iter().enumerate() {
+            dot += *val * matrix[(first_row + idx, col)]
+        }
+
+        let weight = factor * dot;
+        for (idx, val) in reflector.iter().enumerate() {
+            matrix[(first_row + idx, col)] -= weight * *val
+        }
+    }
+}
+
+// Applies the reflector from the right to the columns starting at 'first_col',
+// within the given rows.
+fn reflect_cols<T>(matrix: &mut Matrix<T>, reflector: &[T], factor: T, first_col: usize, rows: Range<usize>)
+where
+    T: Real
+{
+    for row in rows {
+        let params = &mut matrix.row_mut(row)[first_col..first_col + reflector.len()];
+
+        let mut dot = T::zero();
+        for (param, val) in params.iter().zip(reflector) {
+            dot += *param * *val
+        }
+
+        let weight = factor * dot;
+        for (param, val) in params.iter_mut().zip(reflector) {
+            *param -= weight * *val
+        }
+    }
+}
+
+// Rotates a converged 2x2 block with real eigenvalues into upper triangular
+// form, leaving blocks with a complex pair untouched.
+fn split_block<T>(schur: &mut Matrix<T>, vectors: Option<&mut Matrix<T>>, start: usize)
+where
+    T: Real
+{
+    let size = schur.rows();
+    let next = start + 1;
+
+    let a = schur[(start, start)];
+    let b = schur[(start, next)];
+    let c = schur[(next, start)];
+    let d = schur[(next, next)];
+
+    let half_diff = (a - d) * T::from_f64(0.5);
+    let discriminant = half_diff * half_diff + b * c;
+
+    if discriminant < T::zero() {
+        return
+    }
+
+    let root = discriminant.sqrt();
+    let value = if half_diff >= T::zero() { d + half_diff + root } else { d + half_diff - root };
+
+    // Both are eigenvectors of the block, so take the longer of the two.
+    let (lhs, rhs) = (b, value - a);
+    let (alt_lhs, alt_rhs) = (value - d, c);
+
+    let (cosine, sine) = if lhs * lhs + rhs * rhs >= alt_lhs * alt_lhs + alt_rhs * alt_rhs {
+        (lhs, rhs)
+    } else {
+        (alt_lhs, alt_rhs)
+    };
+    let length = (cosine * cosine + sine * sine).sqrt();
+    let (cosine, sine) = (cosine / length, sine / length);
+
+    for col in start..size {
+        let upper = schur[(start, col)];
+        let lower = schur[(next, col)];
+
+        schur[(start, col)] = cosine * upper + sine * lower;
+        schur[(next, col)] = cosine * lower - sine * upper;
+    }
+
+    let rotate_cols = |matrix: &mut Matrix<T>, rows: usize| {
+        for row in 0..rows {
+            let left = matrix[(row, start)];
+            let right = matrix[(row, next)];
+
+            matrix[(row, start)] = cosine * left + sine * right;
+            matrix[(row, next)] = cosine * right - sine * left;
+        }
+    };
+
+    rotate_cols(schur, next + 1);
+    if let Some(vectors) = vectors {
+        rotate_cols(vectors, size)
+    }
+
+    schur[(next, start)] = T::zero();
+}
+
+// Returns the diagonal blocks of the real Schur form in order.
+fn blocks<T>(schur: &Matrix<T>) -> Vec<Range<usize>>
+where
+    T: Real
+{
+    let size = schur.rows();
+    let mut blocks = Vec::with_capacity(size);
+    let mut start = 0;
+
+    while start < size {
+        let len = if start + 1 < size && !schur[(start + 1, start)].is_zero() { 2 } else { 1 };
+
+        blocks.push(start..start + len);
+        start += len
+    }
+
+    blocks
+}
+
+// Returns the eigenvalues of a diagonal block, with
+// the positive imaginary part first for a complex pair.
+fn block_values<T>(schur: &Matrix<T>, block: Range<usize>) -> Vec<Complex<T>>
+where
+    T: Real
+{
+    let start = block.start;
+
+    if block.len() == 1 {
+        return vec![Complex::from(schur[(start, start)])]
+    }
+
+    let a = schur[(start, start)];
+    let d = schur[(start + 1, start + 1)];
+
+    let half_diff = (a - d) * T::from_f64(0.5);
+    let discriminant = half_diff * half_diff + schur[(start, start + 1)] * schur[(start + 1, start)];
+
+    let real = d + half_diff;
+    let imag = (-discriminant).sqrt();
+
+    vec![Complex::new(real, imag), Complex::new(real, -imag)]
+}
+
+// Solves '(T - λI) * x = 0' for the entries of 'x' belonging to the given blocks,
+// which all lie above those already filled in.
+fn back_substitute<T>(schur: &Matrix<T>, blocks: &[Range<usize>], value: Complex<T>, small: T, params: &mut [Complex<T>])
+where
+    T: Real
+{
+    let size = schur.rows();
+
+    let residual = |row: usize, params: &[Complex<T>], from: usize| {
+        let mut sum = Complex::from(T::zero());
+        for (col, param) in params.iter().enumerate().take(size).skip(from) {
+            sum -= Complex::from(schur[(row, col)]) * *param
+        }
+        sum
+    };
+
+    let nonzero = |pivot: Complex<T>| {
+        if pivot.norm() <= small {
+            Complex::from(small)
+        } else {
+            pivot
+        }
+    };
+
+    for block in blocks.iter().rev() {
+        let start = block.start;
+
+        if block.len() == 1 {
+            let pivot = nonzero(Complex::from(schur[(start, start)]) - value);
+
+            params[start] = residual(start, params, start + 1) / pivot
+        } else {
+            let next = start + 1;
+
+            let m00 = Complex::from(schur[(start, start)]) - value;
+            let m01 = Complex::from(schur[(start, next)]);
+            let m10 = Complex::from(schur[(next, start)]);
+            let m11 = Complex::from(schur[(next, next)]) - value;
+
+            let rhs0 = residual(start, params, next + 1);
+            let rhs1 = residual(next, params, next + 1);
+
+            let determinant = nonzero(m00 * m11 - m01 * m10);
+
+            params[start] = (rhs0 * m11 - m01 * rhs1) / determinant;
+            params[next] = (m00 * rhs1 - m10 * rhs0) / determinant;
+        }
+    }
+}
+
+// Maps an eigenvector of the Schur form back through 'Z', scaling it to unit
+// length with its component of largest magnitude real and positive.
+fn normalize<T>(vectors: &Option<Matrix<T>>, params: &[Complex<T>]) -> Vec<Complex<T>>
+where
+    T: Real
+{
+    let vectors = vectors.as_ref().expect("the Schur vectors are accumulated for eigenvectors");
+
+    let mut column: Vec<Complex<T>> = vectors
+        .row_iter()
+        .map(|row| {
+            let mut sum = Complex::from(T::zero());
+            for (val, param) in row.iter().zip(params) {
+                sum += Complex::from(*val) * *param
+            }
+            sum
+        })
+        .collect();
+
+    let mut largest = Complex::from(T::zero());
+    let mut length = T::zero();
+    for val in &column {
+        let norm = val.norm();
+
+        if norm > largest.norm() {
+            largest = *val
+        }
+        length += norm * norm
+    }
+
+    if largest.is_zero() {
+        return column
+    }
+
+    // Dividing by the phase of the largest component rotates it onto the
+    // positive real axis, and dividing by the length normalizes.
+    let scale = largest / Complex::from(largest.norm()) * Complex::from(length.sqrt());
+
+    for val in column.iter_mut() {
+        *val /= scale
+    }
+
+    column
+}
@@ -0,0 +1,336 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError, LinalgError};
+use crate::traits::Real;
+
+/// The LU decomposition of a square matrix with partial pivoting,
+/// created by [Matrix::lu].
+/// 
+/// The decomposition satisfies `P * A = L * U`, where `P` is a permutation
+/// matrix, `L` is unit lower triangular and `U` is upper triangular.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[2.0, 1.0],
+///                      [4.0, 3.0]];
+/// 
+/// let lu = matrix.lu().unwrap();
+/// 
+/// assert_eq!(lu.p() * &matrix, lu.l() * lu.u());
+/// assert_eq!(lu.determinant(), 2.0);
+/// assert_eq!(lu.solve(&vector![3.0, 7.0]), Ok(vector![1.0, 1.0]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LU<T> {
+    // L (below the diagonal, with an implied unit diagonal)
+    // and U (on and above the diagonal) packed together.
+    lu : Matrix<T>,
+    permutation : Vec<usize>,
+    swaps : usize,
+    tolerance : T
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the LU decomposition of a square matrix with partial pivoting.
+    /// 
+    /// The decomposition itself always succeeds for square matrices, including
+    /// singular ones, so that their [determinant][LU::determinant] can still be
+    /// found. Singularity is instead reported by the solvers.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1.0, 2.0],
+    ///                      [3.0, 4.0]];
+    /// 
+    /// let lu = matrix.lu().unwrap();
+    /// 
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// assert_eq!(lu.l(), matrix![[1.0,       0.0],
+    ///                            [1.0 / 3.0, 1.0]]);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square.
+    pub fn lu(&self) -> Result<LU<T>, LinalgError> {
+        if self.rows() != self.cols() {
+            return Err(LinalgError::NotSquare(self.rows(), self.cols()))
+        }
+
+        let size = self.rows();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0;
+
+        let mut max_abs = T::zero();
+        for val in self.iter() {
+            if val.abs() > max_abs {
+                max_abs = val.abs()
+            }
+        }
+
+        // Pivots at or below this magnitude are treated as zero by the solvers.
+        let tolerance = T::epsilon() * T::from_f64(size as f64) * max_abs;
+
+        let params = lu.as_mut_slice();
+
+        for pivot_idx in 0..size {
+            // Choose the remaining row with the largest magnitude in this column.
+            let mut max_row = pivot_idx;
+            for row_idx in pivot_idx + 1..size {
+                if params[row_idx * size + pivot_idx].abs() > params[max_row * size + pivot_idx].abs() {
+                    max_row = row_idx
+                }
+            }
+
+            if max_row != pivot_idx {
+                for col_idx in 0..size {
+                    params.swap(pivot_idx * size + col_idx, max_row * size + col_idx)
+                }
+
+                permutation.swap(pivot_idx, max_row);
+                swaps += 1
+            }
+
+            let pivot = params[pivot_idx * size + pivot_idx];
+
+            // The whole column below is zero, so there is nothing to eliminate.
+            if pivot.is_zero() {
+                continue
+            }
+
+            for row_idx in pivot_idx + 1..size {
+                let factor = params[row_idx * size + pivot_idx] / pivot;
+                params[row_idx * size + pivot_idx] = factor;
+
+                for col_idx in pivot_idx + 1..size {
+                    let upper = params[pivot_idx * size + col_idx];
+                    params[row_idx * size + col_idx] -= factor * upper
+                }
+            }
+        }
+
+        Ok(LU {
+            lu,
+            permutation,
+            swaps,
+            tolerance
+        })
+    }
+}
+
+impl<T> LU<T>
+where
+    T: Real
+{
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> Matrix<T> {
+        let size = self.lu.rows();
+
+        Matrix::from_fn(size, size, |row, col| {
+            if row > col {
+                self.lu[(row, col)]
+            } else if row == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> Matrix<T> {
+        let size = self.lu.rows();
+
+        Matrix::from_fn(size, size, |row, col| {
+            if row <= col {
+                self.lu[(row, col)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the row permutation, where row `i` of `P * A`
+    /// is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the permutation matrix `P`.
+    pub fn p(&self) -> Matrix<T> {
+        let size = self.lu.rows();
+
+        Matrix::from_fn(size, size, |row, col| {
+            if self.permutation[row] == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns true if a pivot is zero, or too small relative
+    /// to the largest element of the matrix to be solved reliably.
+    pub fn is_singular(&self) -> bool {
+        let size = self.lu.rows();
+
+        (0..size).any(|idx| self.lu[(idx, idx)].abs() <= self.tolerance)
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[0.0, 2.0, 0.0],
+    ///                      [3.0, 0.0, 0.0],
+    ///                      [0.0, 0.0, 4.0]];
+    /// 
+    /// assert_eq!(matrix.lu().unwrap().determinant(), -24.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let size = self.lu.rows();
+        let mut det = T::one();
+
+        for idx in 0..size {
+            det *= self.lu[(idx, idx)]
+        }
+
+        if self.swaps % 2 == 1 {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Solves `A * x = b` for `x`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[2.0, 0.0],
+    ///                      [0.0, 4.0]];
+    /// 
+    /// let lu = matrix.lu().unwrap();
+    /// 
+    /// assert_eq!(lu.solve(&vector![2.0, 2.0]), Ok(vector![1.0, 0.5]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let size = self.lu.rows();
+
+        if rhs.len() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Vector(rhs.len())).into())
+        }
+
+        if self.is_singular() {
+            return Err(LinalgError::Singular)
+        }
+
+        // Forward substitution with the permuted right hand side: L * y = P * b.
+        let mut params: Vec<T> = Vec::with_capacity(size);
+        for row_idx in 0..size {
+            let mut param = rhs[self.permutation[row_idx]];
+            let lower = &self.lu.row(row_idx)[..row_idx];
+
+            for (factor, solved) in lower.iter().zip(&params) {
+                param -= *factor * *solved
+            }
+
+            params.push(param)
+        }
+
+        // Back substitution: U * x = y.
+        for row_idx in (0..size).rev() {
+            let mut param = params[row_idx];
+            let upper = &self.lu.row(row_idx)[row_idx + 1..];
+
+            for (factor, solved) in upper.iter().zip(&params[row_idx + 1..]) {
+                param -= *factor * *solved
+            }
+
+            params[row_idx] = param / self.lu[(row_idx, row_idx)]
+        }
+
+        Ok(Vector::from(params))
+    }
+
+    /// Solves `A * X = B` for `X`, treating each column of `B`
+    /// as a separate right hand side.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[2.0, 1.0],
+    ///                      [4.0, 3.0]];
+    /// 
+    /// let lu = matrix.lu().unwrap();
+    /// 
+    /// let solution = lu.solve_matrix(&Matrix::identity(2)).unwrap();
+    /// 
+    /// assert_eq!(&matrix * &solution, Matrix::identity(2));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the number of rows in `B` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, LinalgError> {
+        let size = self.lu.rows();
+        let cols = rhs.cols();
+
+        if rhs.rows() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Matrix(rhs.rows(), cols)).into())
+        }
+
+        if self.is_singular() {
+            return Err(LinalgError::Singular)
+        }
+
+        // Every column is substituted at once, by working on whole rows
+        // of the row-major buffer at a time.
+        let mut params: Vec<T> = Vec::with_capacity(size * cols);
+        for row_idx in 0..size {
+            params.extend_from_slice(rhs.row(self.permutation[row_idx]));
+
+            for col_idx in 0..row_idx {
+                let factor = self.lu[(row_idx, col_idx)];
+
+                for idx in 0..cols {
+                    let solved = params[col_idx * cols + idx];
+                    params[row_idx * cols + idx] -= factor * solved
+                }
+            }
+        }
+
+        for row_idx in (0..size).rev() {
+            for col_idx in row_idx + 1..size {
+                let factor = self.lu[(row_idx, col_idx)];
+
+                for idx in 0..cols {
+                    let solved = params[col_idx * cols + idx];
+                    params[row_idx * cols + idx] -= factor * solved
+                }
+            }
+
+            let pivot = self.lu[(row_idx, row_idx)];
+            for idx in 0..cols {
+                params[row_idx * cols + idx] /= pivot
+            }
+        }
+
+        Ok(Matrix::from_parts(size, cols, params))
+    }
+}
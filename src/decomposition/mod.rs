@@ -0,0 +1,16 @@
+mod cholesky;
+mod eigen;
+mod lu;
+mod qr;
+mod svd;
+mod symmetric_eigen;
+
+pub use crate::decomposition::cholesky::{Cholesky, LDL};
+pub use crate::decomposition::eigen::Eigen;
+pub use crate::decomposition::lu::LU;
+pub use crate::decomposition::qr::QR;
+pub use crate::decomposition::svd::SVD;
+pub use crate::decomposition::symmetric_eigen::SymmetricEigen;
+
+pub(crate) use crate::decomposition::cholesky::symmetric_tolerance;
+pub(crate) use crate::decomposition::svd::jacobi_svd;
@@ -0,0 +1,358 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError, LinalgError};
+use crate::traits::Real;
+
+/// The QR decomposition of a matrix computed with Householder reflections,
+/// created by [Matrix::qr] or [Matrix::qr_pivoted].
+/// 
+/// The decomposition satisfies `A * P = Q * R`, where `Q` is orthogonal,
+/// `R` is upper triangular and `P` is a column permutation. Without
+/// pivoting, `P` is the identity.
+/// 
+/// The matrix may have any shape, and the tall case (`rows > cols`) is the
+/// one used for [least squares][QR::solve_least_squares].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[3.0, 0.0],
+///                      [4.0, 1.0],
+///                      [0.0, 2.0]];
+/// 
+/// let qr = matrix.qr();
+/// 
+/// assert_eq!(qr.q().rows(), 3);
+/// assert_eq!(qr.thin_q().cols(), 2);
+/// assert_eq!(qr.r()[(0, 0)], -5.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QR<T> {
+    // R on and above the diagonal, with the Householder vectors (whose
+    // leading 1 is implied) stored below it.
+    qr : Matrix<T>,
+    tau : Vec<T>,
+    permutation : Vec<usize>
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the QR decomposition of the matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 2.0],
+    ///                                   [3.0, 4.0],
+    ///                                   [5.0, 6.0]];
+    /// 
+    /// let qr = matrix.qr();
+    /// let product = qr.thin_q() * qr.thin_r();
+    /// 
+    /// for (lhs, rhs) in product.iter().zip(matrix.iter()) {
+    ///     assert!((lhs - rhs).abs() < 1e-12)
+    /// }
+    /// ```
+    pub fn qr(&self) -> QR<T> {
+        householder(self, false)
+    }
+
+    /// Computes the QR decomposition of the matrix with column pivoting.
+    /// 
+    /// At each step the remaining column with the largest norm is moved
+    /// into place, so the magnitudes along the diagonal of `R` are
+    /// non-increasing and [rank][QR::rank] reveals dependent columns.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// // The third column is the sum of the first two.
+    /// let matrix = matrix![[1.0, 0.0, 1.0],
+    ///                      [0.0, 1.0, 1.0],
+    ///                      [1.0, 1.0, 2.0],
+    ///                      [2.0, 0.0, 2.0]];
+    /// 
+    /// let qr = matrix.qr_pivoted();
+    /// 
+    /// assert_eq!(qr.permutation()[0], 2);
+    /// assert_eq!(qr.rank(1e-10), 2);
+    /// ```
+    pub fn qr_pivoted(&self) -> QR<T> {
+        householder(self, true)
+    }
+}
+
+fn householder<T>(matrix: &Matrix<T>, pivoting: bool) -> QR<T>
+where
+    T: Real
+{
+    let rows = matrix.rows();
+    let cols = matrix.cols();
+    let steps = rows.min(cols);
+
+    let mut qr = matrix.clone();
+    let mut tau = Vec::with_capacity(steps);
+    let mut permutation: Vec<usize> = (0..cols).collect();
+
+    for step in 0..steps {
+        if pivoting {
+            let norm = |qr: &Matrix<T>, col: usize| {
+                let mut norm = T::zero();
+                for row in step..rows {
+                    norm += qr[(row, col)] * qr[(row, col)]
+                }
+                norm
+            };
+
+            let mut max_col = step;
+            let mut max_norm = norm(&qr, step);
+
+            for col in step + 1..cols {
+                let col_norm = norm(&qr, col);
+
+                if col_norm > max_norm {
+                    max_col = col;
+                    max_norm = col_norm
+                }
+            }
+
+            if max_col != step {
+                for row in 0..rows {
+                    qr.as_mut_slice().swap(row * cols + step, row * cols + max_col)
+                }
+
+                permutation.swap(step, max_col)
+            }
+        }
+
+        // Build the reflector H = I - tau * v * vᵀ mapping the column
+        // below the diagonal onto a multiple of the first basis vector.
+        let head = qr[(step, step)];
+        let mut tail_norm = T::zero();
+        for row in step + 1..rows {
+            tail_norm += qr[(row, step)] * qr[(row, step)]
+        }
+
+        if tail_norm.is_zero() {
+            tau.push(T::zero());
+            continue
+        }
+
+        let norm = (head * head + tail_norm).sqrt();
+        let beta = if head >= T::zero() { -norm } else { norm };
+        let scale = T::one() / (head - beta);
+
+        for row in step + 1..rows {
+            qr[(row, step)] *= scale
+        }
+        qr[(step, step)] = beta;
+
+        let factor = (beta - head) / beta;
+        tau.push(factor);
+
+        // Apply the reflector to the remaining columns.
+        for col in step + 1..cols {
+            let mut dot = qr[(step, col)];
+            for row in step + 1..rows {
+                dot += qr[(row, step)] * qr[(row, col)]
+            }
+
+            let weight = factor * dot;
+
+            qr[(step, col)] -= weight;
+            for row in step + 1..rows {
+                let reflected = qr[(row, step)];
+                qr[(row, col)] -= weight * reflected
+            }
+        }
+    }
+
+    QR {
+        qr,
+        tau,
+        permutation
+    }
+}
+
+impl<T> QR<T>
+where
+    T: Real
+{
+    /// Returns the full `rows x rows` orthogonal factor `Q`.
+    pub fn q(&self) -> Matrix<T> {
+        self.accumulate_q(self.qr.rows())
+    }
+
+    /// Returns the thin `rows x min(rows, cols)` factor `Q`,
+    /// whose columns are orthonormal.
+    pub fn thin_q(&self) -> Matrix<T> {
+        self.accumulate_q(self.tau.len())
+    }
+
+    /// Returns the full `rows x cols` upper triangular factor `R`.
+    pub fn r(&self) -> Matrix<T> {
+        self.upper(self.qr.rows())
+    }
+
+    /// Returns the thin `min(rows, cols) x cols` upper triangular factor `R`.
+    pub fn thin_r(&self) -> Matrix<T> {
+        self.upper(self.tau.len())
+    }
+
+    /// Returns the column permutation, where column `j` of `A * P`
+    /// is column `permutation()[j]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the column permutation matrix `P`.
+    pub fn p(&self) -> Matrix<T> {
+        let cols = self.qr.cols();
+
+        Matrix::from_fn(cols, cols, |row, col| {
+            if self.permutation[col] == row {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Returns the number of diagonal elements of `R` whose magnitude
+    /// exceeds `tolerance`.
+    /// 
+    /// This is only a reliable estimate of the rank when the decomposition
+    /// was computed with [column pivoting][Matrix::qr_pivoted].
+    pub fn rank(&self, tolerance: T) -> usize {
+        (0..self.tau.len())
+            .filter(|idx| self.qr[(*idx, *idx)].abs() > tolerance)
+            .count()
+    }
+
+    /// Finds the `x` minimizing `|A * x - b|`, which is the exact
+    /// solution when `A` is square and non-singular.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// // Fit a line through (0, 1), (1, 3) and (2, 5).
+    /// let matrix: Matrix<f64> = matrix![[1.0, 0.0],
+    ///                                   [1.0, 1.0],
+    ///                                   [1.0, 2.0]];
+    /// 
+    /// let solution = matrix.qr().solve_least_squares(&vector![1.0, 3.0, 5.0]).unwrap();
+    /// 
+    /// assert!((solution[0] - 1.0).abs() < 1e-12);
+    /// assert!((solution[1] - 2.0).abs() < 1e-12);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to the number
+    /// of rows, or [LinalgError::Singular] if the columns are linearly dependent,
+    /// which includes every matrix with fewer rows than columns.
+    pub fn solve_least_squares(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError> {
+        let rows = self.qr.rows();
+        let cols = self.qr.cols();
+
+        if rhs.len() != rows {
+            return Err(ShapeError::new("solve_least_squares", Shape::Matrix(rows, cols), Shape::Vector(rhs.len())).into())
+        }
+
+        if rows < cols {
+            return Err(LinalgError::Singular)
+        }
+
+        let mut max_diagonal = T::zero();
+        for idx in 0..cols {
+            if self.qr[(idx, idx)].abs() > max_diagonal {
+                max_diagonal = self.qr[(idx, idx)].abs()
+            }
+        }
+
+        let tolerance = T::epsilon() * T::from_f64(rows as f64) * max_diagonal;
+        if max_diagonal.is_zero() || self.rank(tolerance) < cols {
+            return Err(LinalgError::Singular)
+        }
+
+        // Qᵀ * b, by applying each reflector in turn.
+        let mut params: Vec<T> = rhs.iter().copied().collect();
+        for (step, factor) in self.tau.iter().enumerate() {
+            self.reflect(step, *factor, &mut params)
+        }
+
+        // Back substitution with the leading square block of R.
+        for row in (0..cols).rev() {
+            let mut param = params[row];
+
+            for (col, solved) in params.iter().enumerate().take(cols).skip(row + 1) {
+                param -= self.qr[(row, col)] * *solved
+            }
+
+            params[row] = param / self.qr[(row, row)]
+        }
+
+        // Undo the column permutation.
+        let mut solution = vec![T::zero(); cols];
+        for (idx, col) in self.permutation.iter().enumerate() {
+            solution[*col] = params[idx]
+        }
+
+        Ok(Vector::from(solution))
+    }
+
+    // Applies the reflector of the given step to a column stored in 'params'.
+    fn reflect(&self, step: usize, factor: T, params: &mut [T]) {
+        if factor.is_zero() {
+            return
+        }
+
+        let rows = self.qr.rows();
+        let mut dot = params[step];
+        for (row, param) in params.iter().enumerate().take(rows).skip(step + 1) {
+            dot += self.qr[(row, step)] * *param
+        }
+
+        let weight = factor * dot;
+
+        params[step] -= weight;
+        for (row, param) in params.iter_mut().enumerate().take(rows).skip(step + 1) {
+            *param -= weight * self.qr[(row, step)]
+        }
+    }
+
+    // Builds the first 'cols' columns of Q = H_0 * H_1 * ... * H_(k-1).
+    fn accumulate_q(&self, cols: usize) -> Matrix<T> {
+        let rows = self.qr.rows();
+        let mut q: Matrix<T> = Matrix::zeros(cols, rows);
+
+        // Each row of 'q' holds a column of Q, so the reflectors
+        // can be applied to contiguous slices before transposing.
+        for col in 0..cols {
+            let column = q.row_mut(col);
+            column[col] = T::one();
+
+            for (step, factor) in self.tau.iter().enumerate().rev() {
+                self.reflect(step, *factor, column)
+            }
+        }
+
+        q.into_transpose()
+    }
+
+    // Extracts the first 'rows' rows of R.
+    fn upper(&self, rows: usize) -> Matrix<T> {
+        Matrix::from_fn(rows, self.qr.cols(), |row, col| {
+            if row <= col {
+                self.qr[(row, col)]
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
@@ -0,0 +1,468 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::LinalgError;
+use crate::traits::Real;
+
+// The number of sweeps after which the one-sided Jacobi iteration gives up
+// with LinalgError::NoConvergence. Convergence is quadratic once the columns
+// are nearly orthogonal, so in practice only a handful of sweeps are ever needed.
+const MAX_SWEEPS: usize = 64;
+
+// The '(U, sigma, V)' factors returned by 'jacobi_svd'.
+pub(crate) type JacobiFactors<T> = (Matrix<T>, Vec<T>, Matrix<T>);
+
+/// The singular value decomposition of a matrix, created by
+/// [Matrix::svd] or [Matrix::full_svd].
+/// 
+/// The decomposition satisfies `A = U * Σ * Vᵀ`, where `U` and `V` have
+/// orthonormal columns and `Σ` is diagonal with the non-negative singular
+/// values in descending order along it.
+/// 
+/// For a `rows x cols` matrix with `k = min(rows, cols)`, the thin form has
+/// `U` as `rows x k` and `Vᵀ` as `k x cols`, while the full form has `U` as
+/// `rows x rows` and `Vᵀ` as `cols x cols`. Both hold `k` singular values.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix: Matrix<f64> = matrix![[3.0, 0.0],
+///                                   [4.0, 5.0]];
+/// 
+/// let svd = matrix.svd().unwrap();
+/// let sigma = Matrix::from_diagonal(svd.singular_values());
+/// let product = svd.u() * sigma * svd.vt();
+/// 
+/// for (lhs, rhs) in product.iter().zip(matrix.iter()) {
+///     assert!((lhs - rhs).abs() < 1e-12)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SVD<T> {
+    u : Matrix<T>,
+    singular_values : Vector<T>,
+    vt : Matrix<T>
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the thin singular value decomposition of the matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 0.0, 1.0],
+    ///                                   [0.0, 1.0, 1.0]];
+    /// 
+    /// let svd = matrix.svd().unwrap();
+    /// 
+    /// assert_eq!((svd.u().rows(), svd.u().cols()), (2, 2));
+    /// assert_eq!((svd.vt().rows(), svd.vt().cols()), (2, 3));
+    /// assert!((svd.singular_values()[0] - 3f64.sqrt()).abs() < 1e-12);
+    /// assert!((svd.singular_values()[1] - 1.0).abs() < 1e-12);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NoConvergence] if the Jacobi sweeps do not
+    /// orthogonalize the columns within their iteration limit, such as
+    /// when the matrix contains NaN.
+    pub fn svd(&self) -> Result<SVD<T>, LinalgError> {
+        decompose(self, false)
+    }
+
+    /// Computes the full singular value decomposition of the matrix,
+    /// where `U` and `Vᵀ` are both square and orthogonal.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 0.0, 1.0],
+    ///                                   [0.0, 1.0, 1.0]];
+    /// 
+    /// let svd = matrix.full_svd().unwrap();
+    /// 
+    /// assert_eq!((svd.u().rows(), svd.u().cols()), (2, 2));
+    /// assert_eq!((svd.vt().rows(), svd.vt().cols()), (3, 3));
+    /// assert_eq!(svd.singular_values().len(), 2);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NoConvergence] if the Jacobi sweeps do not
+    /// converge, as with [svd][Matrix::svd].
+    pub fn full_svd(&self) -> Result<SVD<T>, LinalgError> {
+        decompose(self, true)
+    }
+
+    /// Computes only the singular values of the matrix, in descending order.
+    /// 
+    /// This skips accumulating the singular vectors, and reduces tall
+    /// matrices to a square triangle with a [QR][Matrix::qr] decomposition
+    /// first, so it is considerably cheaper than [svd][Matrix::svd].
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[2.0, 0.0],
+    ///                                   [0.0, 3.0],
+    ///                                   [0.0, 0.0]];
+    /// 
+    /// assert_eq!(matrix.singular_values(), Ok(vector![3.0, 2.0]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NoConvergence] if the Jacobi sweeps do not
+    /// converge, as with [svd][Matrix::svd].
+    pub fn singular_values(&self) -> Result<Vector<T>, LinalgError> {
+        if self.rows() < self.cols() {
+            return self.transpose().singular_values()
+        }
+
+        let reduced = if self.rows() > self.cols() {
+            self.qr().thin_r()
+        } else {
+            self.clone()
+        };
+
+        Ok(Vector::from(jacobi_values(reduced)?))
+    }
+}
+
+fn decompose<T>(matrix: &Matrix<T>, full: bool) -> Result<SVD<T>, LinalgError>
+where
+    T: Real
+{
+    // The decomposition of Aᵀ = U * Σ * Vᵀ gives A = V * Σ * Uᵀ.
+    if matrix.rows() < matrix.cols() {
+        let SVD { u, singular_values, vt } = decompose(&matrix.transpose(), full)?;
+
+        return Ok(SVD {
+            u : vt.into_transpose(),
+            singular_values,
+            vt : u.into_transpose()
+        })
+    }
+
+    let (u, sigma, v) = jacobi_svd(matrix)?;
+
+    // The columns of U for zero singular values were left as zero,
+    // so they are replaced along with any columns of the full form.
+    let nonzero = sigma.iter().take_while(|value| !value.is_zero()).count();
+    let cols = if full { matrix.rows() } else { matrix.cols() };
+
+    Ok(SVD {
+        u : complete_basis(&u, nonzero, cols),
+        singular_values : Vector::from(sigma),
+        vt : v.into_transpose()
+    })
+}
+
+impl<T> SVD<T>
+where
+    T: Real
+{
+    /// Returns the left singular vectors `U` as columns.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Returns the singular values in descending order.
+    pub fn singular_values(&self) -> &Vector<T> {
+        &self.singular_values
+    }
+
+    /// Returns the right singular vectors `V` as the rows of `Vᵀ`.
+    pub fn vt(&self) -> &Matrix<T> {
+        &self.vt
+    }
+
+    /// Returns the number of singular values greater than `tolerance`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 2.0],
+    ///                                   [2.0, 4.0],
+    ///                                   [3.0, 6.0]];
+    /// 
+    /// assert_eq!(matrix.svd().unwrap().rank(1e-10), 1);
+    /// ```
+    pub fn rank(&self, tolerance: T) -> usize {
+        self.singular_values
+            .iter()
+            .filter(|value| **value > tolerance)
+            .count()
+    }
+
+    /// Returns the 2-norm condition number `σ_max / σ_min`,
+    /// which is infinite when the matrix is singular.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[4.0, 0.0],
+    ///                                   [0.0, 0.5]];
+    /// 
+    /// assert_eq!(matrix.svd().unwrap().condition_number(), 8.0);
+    /// ```
+    pub fn condition_number(&self) -> T {
+        match (self.singular_values.iter().next(), self.singular_values.iter().last()) {
+            (Some(_), Some(min)) if min.is_zero() => T::from_f64(f64::INFINITY),
+            (Some(max), Some(min)) => *max / *min,
+            _ => T::one()
+        }
+    }
+
+    /// Returns an orthonormal basis of the null space of the matrix
+    /// as the columns of a `cols x nullity` matrix.
+    /// 
+    /// Singular values at or below `ε * max(rows, cols) * σ_max`
+    /// are treated as zero.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 1.0, 0.0],
+    ///                                   [0.0, 0.0, 1.0]];
+    /// 
+    /// let null_space = matrix.svd().unwrap().null_space();
+    /// 
+    /// assert_eq!(null_space.cols(), 1);
+    /// 
+    /// for val in (&matrix * &null_space).iter() {
+    ///     assert!(val.abs() < 1e-12)
+    /// }
+    /// ```
+    pub fn null_space(&self) -> Matrix<T> {
+        let cols = self.vt.cols();
+        let size = self.u.rows().max(cols);
+
+        let max_value = self.singular_values.iter().next().copied().unwrap_or(T::zero());
+        let rank = self.rank(T::epsilon() * T::from_f64(size as f64) * max_value);
+
+        let v = complete_basis(&self.vt.transpose(), rank, cols);
+
+        Matrix::from_fn(cols, cols - rank, |row, col| v[(row, rank + col)])
+    }
+
+    /// Returns the best approximation of the matrix with rank at most `k`,
+    /// keeping only the `k` largest singular values.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[3.0, 0.0],
+    ///                                   [0.0, 1.0]];
+    /// 
+    /// let approx = matrix.svd().unwrap().low_rank_approx(1);
+    /// 
+    /// assert_eq!(approx, matrix![[3.0, 0.0],
+    ///                            [0.0, 0.0]]);
+    /// ```
+    pub fn low_rank_approx(&self, k: usize) -> Matrix<T> {
+        let rows = self.u.rows();
+        let cols = self.vt.cols();
+
+        let mut approx = Matrix::zeros(rows, cols);
+
+        for (idx, value) in self.singular_values.iter().enumerate().take(k) {
+            for row in 0..rows {
+                let scaled = self.u[(row, idx)] * *value;
+
+                for (val, singular) in approx.row_mut(row).iter_mut().zip(self.vt.row(idx)) {
+                    *val += scaled * *singular
+                }
+            }
+        }
+
+        approx
+    }
+}
+
+/// Computes the thin singular value decomposition of a matrix with at least
+/// as many rows as columns, using one-sided Jacobi rotations.
+/// 
+/// Returns `(U, sigma, V)` where `U` is `rows x cols` with orthonormal columns,
+/// `sigma` holds the singular values in descending order, and `V` is the
+/// `cols x cols` orthogonal matrix such that `A = U * diag(sigma) * Vᵀ`.
+/// Columns of `U` belonging to zero singular values are left as zero.
+pub(crate) fn jacobi_svd<T>(matrix: &Matrix<T>) -> Result<JacobiFactors<T>, LinalgError>
+where
+    T: Real
+{
+    debug_assert!(matrix.rows() >= matrix.cols());
+
+    let rows = matrix.rows();
+    let cols = matrix.cols();
+
+    let mut u = matrix.clone();
+    let mut v: Matrix<T> = Matrix::identity(cols);
+
+    orthogonalize(&mut u, Some(&mut v))?;
+
+    let sigma = column_norms(&u);
+
+    // Normalize the columns of U, leaving those of zero singular values as zero.
+    for (col, value) in sigma.iter().enumerate() {
+        for row in 0..rows {
+            u[(row, col)] = if value.is_zero() {
+                T::zero()
+            } else {
+                u[(row, col)] / *value
+            }
+        }
+    }
+
+    // Sort the singular values in descending order, permuting U and V alongside.
+    let order = descending(&sigma);
+
+    let u = Matrix::from_fn(rows, cols, |row, col| u[(row, order[col])]);
+    let v = Matrix::from_fn(cols, cols, |row, col| v[(row, order[col])]);
+    let sigma = order.iter().map(|idx| sigma[*idx]).collect();
+
+    Ok((u, sigma, v))
+}
+
+// Computes only the singular values, in descending order, of a matrix
+// with at least as many rows as columns.
+fn jacobi_values<T>(mut matrix: Matrix<T>) -> Result<Vec<T>, LinalgError>
+where
+    T: Real
+{
+    orthogonalize(&mut matrix, None)?;
+
+    let sigma = column_norms(&matrix);
+
+    Ok(descending(&sigma).iter().map(|idx| sigma[*idx]).collect())
+}
+
+// Rotates pairs of columns of 'u' until they are mutually orthogonal,
+// accumulating the same rotations into 'v' when it is given.
+fn orthogonalize<T>(u: &mut Matrix<T>, mut v: Option<&mut Matrix<T>>) -> Result<(), LinalgError>
+where
+    T: Real
+{
+    let cols = u.cols();
+    let two = T::from_f64(2.0);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..cols {
+            for q in p + 1..cols {
+                let mut alpha = T::zero();
+                let mut beta = T::zero();
+                let mut gamma = T::zero();
+
+                for row in u.row_iter() {
+                    alpha += row[p] * row[p];
+                    beta += row[q] * row[q];
+                    gamma += row[p] * row[q];
+                }
+
+                // The columns are already orthogonal to working precision.
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue
+                }
+
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let tangent = if zeta >= T::zero() {
+                    T::one() / (zeta + (T::one() + zeta * zeta).sqrt())
+                } else {
+                    -T::one() / (-zeta + (T::one() + zeta * zeta).sqrt())
+                };
+                let cosine = T::one() / (T::one() + tangent * tangent).sqrt();
+                let sine = cosine * tangent;
+
+                rotate_cols(u, p, q, cosine, sine);
+                if let Some(v) = v.as_deref_mut() {
+                    rotate_cols(v, p, q, cosine, sine)
+                }
+            }
+        }
+
+        if !rotated {
+            return Ok(())
+        }
+    }
+
+    Err(LinalgError::NoConvergence)
+}
+
+// Returns the Euclidean norm of every column.
+fn column_norms<T>(matrix: &Matrix<T>) -> Vec<T>
+where
+    T: Real
+{
+    let mut norms = vec![T::zero(); matrix.cols()];
+    for row in matrix.row_iter() {
+        for (norm, val) in norms.iter_mut().zip(row) {
+            *norm += *val * *val
+        }
+    }
+    for norm in norms.iter_mut() {
+        *norm = norm.sqrt()
+    }
+
+    norms
+}
+
+// Returns the indices that sort the values in descending order.
+fn descending<T>(values: &[T]) -> Vec<usize>
+where
+    T: Real
+{
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|lhs, rhs| values[*rhs].partial_cmp(&values[*lhs]).unwrap_or(std::cmp::Ordering::Equal));
+
+    order
+}
+
+// Extends the first 'rank' columns of 'basis', which must be orthonormal,
+// with further orthonormal columns up to a total of 'cols'.
+fn complete_basis<T>(basis: &Matrix<T>, rank: usize, cols: usize) -> Matrix<T>
+where
+    T: Real
+{
+    let rows = basis.rows();
+
+    if rank == cols && basis.cols() == cols {
+        return basis.clone()
+    }
+
+    // The leading columns of Q span the same space as the basis,
+    // so the trailing ones span its orthogonal complement.
+    let q = Matrix::from_fn(rows, rank, |row, col| basis[(row, col)]).qr().q();
+
+    Matrix::from_fn(rows, cols, |row, col| {
+        if col < rank {
+            basis[(row, col)]
+        } else {
+            q[(row, col)]
+        }
+    })
+}
+
+// Applies the Jacobi rotation to columns 'p' and 'q' of the matrix.
+fn rotate_cols<T>(matrix: &mut Matrix<T>, p: usize, q: usize, cosine: T, sine: T)
+where
+    T: Real
+{
+    for row in 0..matrix.rows() {
+        let val_p = matrix[(row, p)];
+        let val_q = matrix[(row, q)];
+
+        matrix[(row, p)] = cosine * val_p - sine * val_q;
+        matrix[(row, q)] = sine * val_p + cosine * val_q;
+    }
+}
@@ -0,0 +1,211 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::decomposition::symmetric_tolerance;
+use crate::error::LinalgError;
+use crate::traits::Real;
+
+// The number of sweeps after which the Jacobi eigenvalue iteration gives up
+// with LinalgError::NoConvergence. Convergence is quadratic, so in practice fewer than ten are ever needed.
+const MAX_SWEEPS: usize = 64;
+
+/// The eigendecomposition of a symmetric matrix,
+/// created by [Matrix::symmetric_eigen].
+/// 
+/// The decomposition satisfies `A = V * Λ * Vᵀ`, where `Λ` is diagonal with
+/// the real eigenvalues along it and `V` is orthogonal with the matching
+/// eigenvectors as its columns.
+/// 
+/// The eigenvalues are sorted in ascending order. Since an eigenvector is
+/// only defined up to its sign, each one is oriented so that its component
+/// of largest magnitude is positive, taking the first such component on a tie.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix: Matrix<f64> = matrix![[2.0, 1.0],
+///                                   [1.0, 2.0]];
+/// 
+/// let eigen = matrix.symmetric_eigen().unwrap();
+/// 
+/// assert!((eigen.eigenvalues()[0] - 1.0).abs() < 1e-12);
+/// assert!((eigen.eigenvalues()[1] - 3.0).abs() < 1e-12);
+/// 
+/// // The eigenvector of 3 is (1, 1) / √2.
+/// let root_half = 0.5f64.sqrt();
+/// assert!((eigen.eigenvectors()[(0, 1)] - root_half).abs() < 1e-12);
+/// assert!((eigen.eigenvectors()[(1, 1)] - root_half).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T> {
+    eigenvalues : Vector<T>,
+    eigenvectors : Matrix<T>
+}
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix
+    /// using cyclic Jacobi rotations.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[ 2.0, -1.0,  0.0],
+    ///                                   [-1.0,  2.0, -1.0],
+    ///                                   [ 0.0, -1.0,  2.0]];
+    /// 
+    /// let eigen = matrix.symmetric_eigen().unwrap();
+    /// let vectors = eigen.eigenvectors();
+    /// let values = Matrix::from_diagonal(eigen.eigenvalues());
+    /// 
+    /// for (lhs, rhs) in (vectors * values * vectors.transpose()).iter().zip(matrix.iter()) {
+    ///     assert!((lhs - rhs).abs() < 1e-12)
+    /// }
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square,
+    /// [LinalgError::NotSymmetric] if it is not symmetric, or
+    /// [LinalgError::NoConvergence] if the rotations fail to converge,
+    /// such as when the matrix contains NaN.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, LinalgError> {
+        symmetric_tolerance(self)?;
+
+        let size = self.rows();
+        let half = T::from_f64(0.5);
+
+        // Average out any asymmetry that was within the tolerance.
+        let mut matrix = Matrix::from_fn(size, size, |row, col| {
+            (self[(row, col)] + self[(col, row)]) * half
+        });
+        let mut vectors: Matrix<T> = Matrix::identity(size);
+
+        let mut norm = T::zero();
+        for val in matrix.iter() {
+            norm += *val * *val
+        }
+        let threshold = T::epsilon() * T::epsilon() * norm;
+
+        let mut converged = false;
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off_diagonal = T::zero();
+            for row in 0..size {
+                for col in row + 1..size {
+                    off_diagonal += matrix[(row, col)] * matrix[(row, col)]
+                }
+            }
+
+            if off_diagonal <= threshold {
+                converged = true;
+                break
+            }
+
+            for p in 0..size {
+                for q in p + 1..size {
+                    let pivot = matrix[(p, q)];
+
+                    if pivot.is_zero() {
+                        continue
+                    }
+
+                    // Choose the rotation that zeroes the (p, q) element,
+                    // taking the smaller of the two possible angles.
+                    let theta = (matrix[(q, q)] - matrix[(p, p)]) / (pivot + pivot);
+                    let root = (theta * theta + T::one()).sqrt();
+                    let tangent = if theta >= T::zero() {
+                        T::one() / (theta + root)
+                    } else {
+                        -T::one() / (-theta + root)
+                    };
+                    let cosine = T::one() / (tangent * tangent + T::one()).sqrt();
+                    let sine = tangent * cosine;
+
+                    rotate(&mut matrix, p, q, cosine, sine);
+                    rotate_vectors(&mut vectors, p, q, cosine, sine)
+                }
+            }
+        }
+
+        if !converged {
+            return Err(LinalgError::NoConvergence)
+        }
+
+        // Sort the eigenvalues in ascending order, permuting the eigenvectors alongside.
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|lhs, rhs| {
+            matrix[(*lhs, *lhs)].partial_cmp(&matrix[(*rhs, *rhs)]).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let eigenvalues = order.iter().map(|idx| matrix[(*idx, *idx)]).collect();
+        let mut eigenvectors = Matrix::from_fn(size, size, |row, col| vectors[(row, order[col])]);
+
+        // Orient each eigenvector so that its largest component is positive.
+        for col in 0..size {
+            let mut largest = T::zero();
+            for row in 0..size {
+                if eigenvectors[(row, col)].abs() > largest.abs() {
+                    largest = eigenvectors[(row, col)]
+                }
+            }
+
+            if largest < T::zero() {
+                for row in 0..size {
+                    eigenvectors[(row, col)] = -eigenvectors[(row, col)]
+                }
+            }
+        }
+
+        Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors
+        })
+    }
+}
+
+// Applies the rotation to both rows and columns 'p' and 'q' of the matrix.
+fn rotate<T>(matrix: &mut Matrix<T>, p: usize, q: usize, cosine: T, sine: T)
+where
+    T: Real
+{
+    rotate_vectors(matrix, p, q, cosine, sine);
+
+    let size = matrix.rows();
+    for col in 0..size {
+        let val_p = matrix[(p, col)];
+        let val_q = matrix[(q, col)];
+
+        matrix[(p, col)] = cosine * val_p - sine * val_q;
+        matrix[(q, col)] = sine * val_p + cosine * val_q;
+    }
+}
+
+// Applies the rotation to columns 'p' and 'q' of the matrix.
+fn rotate_vectors<T>(matrix: &mut Matrix<T>, p: usize, q: usize, cosine: T, sine: T)
+where
+    T: Real
+{
+    for row in 0..matrix.rows() {
+        let val_p = matrix[(row, p)];
+        let val_q = matrix[(row, q)];
+
+        matrix[(row, p)] = cosine * val_p - sine * val_q;
+        matrix[(row, q)] = sine * val_p + cosine * val_q;
+    }
+}
+
+impl<T> SymmetricEigen<T> {
+    /// Returns the eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> &Vector<T> {
+        &self.eigenvalues
+    }
+
+    /// Returns the orthonormal eigenvectors as columns, where column `i`
+    /// belongs to the eigenvalue at index `i`.
+    pub fn eigenvectors(&self) -> &Matrix<T> {
+        &self.eigenvectors
+    }
+}
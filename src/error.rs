@@ -0,0 +1,146 @@
+use std::error::Error;
+use std::fmt;
+
+/// The shape of an operand taking part in a fallible operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Shape {
+    /// A [Vector][crate::vector_impl::Vector] with the given length.
+    Vector(usize),
+
+    /// A [Matrix][crate::matrix_impl::Matrix] with the given rows and columns.
+    Matrix(usize, usize)
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Vector(len) => write!(f, "vector of length {}", len),
+            Shape::Matrix(rows, cols) => write!(f, "{}x{} matrix", rows, cols)
+        }
+    }
+}
+
+/// The error returned when the operands of an operation have incompatible shapes.
+/// 
+/// It records the name of the operation along with the shapes of
+/// both the left hand side and right hand side operands.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::error::Shape;
+/// 
+/// let vector1 = vector![1, 2, 3];
+/// let vector2 = vector![4, 5];
+/// 
+/// let error = vector1.checked_add(&vector2).unwrap_err();
+/// 
+/// assert_eq!(error.operation(), "add");
+/// assert_eq!(error.lhs(), Shape::Vector(3));
+/// assert_eq!(error.rhs(), Shape::Vector(2));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ShapeError {
+    operation : &'static str,
+    lhs : Shape,
+    rhs : Shape
+}
+
+impl ShapeError {
+    pub(crate) fn new(operation: &'static str, lhs: Shape, rhs: Shape) -> ShapeError {
+        ShapeError {
+            operation,
+            lhs,
+            rhs
+        }
+    }
+
+    /// Returns the name of the operation that failed.
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// Returns the shape of the left hand side operand.
+    pub fn lhs(&self) -> Shape {
+        self.lhs
+    }
+
+    /// Returns the shape of the right hand side operand.
+    pub fn rhs(&self) -> Shape {
+        self.rhs
+    }
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: incompatible shapes {} and {}", self.operation, self.lhs, self.rhs)
+    }
+}
+
+impl Error for ShapeError {}
+
+/// The error returned by the decompositions and the solvers built on them.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::error::LinalgError;
+/// 
+/// let matrix = matrix![[1.0, 2.0],
+///                      [2.0, 4.0]];
+/// 
+/// let lu = matrix.lu().unwrap();
+/// 
+/// assert_eq!(lu.solve(&vector![1.0, 1.0]), Err(LinalgError::Singular));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinalgError {
+    /// The operands have incompatible shapes.
+    Shape(ShapeError),
+
+    /// The operation requires a square matrix, but the
+    /// matrix has the given rows and columns.
+    NotSquare(usize, usize),
+
+    /// The matrix is singular, or too close to singular
+    /// to be solved reliably.
+    Singular,
+
+    /// The operation requires a symmetric matrix.
+    NotSymmetric,
+
+    /// The operation requires a positive definite matrix
+    /// (or positive semi-definite, where documented).
+    NotPositiveDefinite,
+
+    /// An iterative algorithm did not converge within its iteration limit.
+    NoConvergence
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::Shape(error) => write!(f, "{}", error),
+            LinalgError::NotSquare(rows, cols) => write!(f, "expected a square matrix, found a {}x{} matrix", rows, cols),
+            LinalgError::Singular => write!(f, "the matrix is singular"),
+            LinalgError::NotSymmetric => write!(f, "the matrix is not symmetric"),
+            LinalgError::NotPositiveDefinite => write!(f, "the matrix is not positive definite"),
+            LinalgError::NoConvergence => write!(f, "the iteration did not converge")
+        }
+    }
+}
+
+impl Error for LinalgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LinalgError::Shape(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<ShapeError> for LinalgError {
+    fn from(error: ShapeError) -> Self {
+        LinalgError::Shape(error)
+    }
+}
@@ -4,6 +4,27 @@ pub mod vector_impl;
 /// Module hosting the [Matrix][crate::matrix_impl::Matrix] type and its implementations.
 pub mod matrix_impl;
 
+/// Module hosting the fixed-size [SVector][crate::static_impl::SVector] and [SMatrix][crate::static_impl::SMatrix] types.
+pub mod static_impl;
+
+/// Module hosting the sparse [CooMatrix][crate::sparse::CooMatrix], [CsrMatrix][crate::sparse::CsrMatrix] and [CscMatrix][crate::sparse::CscMatrix] types.
+pub mod sparse;
+
+/// Module hosting the structured matrix types, such as [Tridiagonal][crate::structured::Tridiagonal] and [UpperTriangular][crate::structured::UpperTriangular].
+pub mod structured;
+
+/// Module hosting the [ShapeError][crate::error::ShapeError] and [LinalgError][crate::error::LinalgError] types returned by fallible operations.
+pub mod error;
+
+/// Module hosting the numeric traits, such as [Ring][crate::traits::Ring] and [Field][crate::traits::Field], that bound the arithmetic.
+pub mod traits;
+
+/// Module hosting the [Complex][crate::complex::Complex] number type.
+pub mod complex;
+
+/// Module hosting the matrix decompositions, such as [LU][crate::decomposition::LU].
+pub mod decomposition;
+
 /// Imports the [Vector][crate::vector_impl::Vector] and [Matrix][crate::matrix_impl::Matrix] types.
 pub mod prelude;
 
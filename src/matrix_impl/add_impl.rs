@@ -42,14 +42,12 @@ where
             panic!("Differently sized matrices cannot be added together.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-        for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                params[row_idx].push(self.matrix[row_idx][col_idx] + rhs.matrix[row_idx][col_idx])
-            }
-        }
+        let params = self.matrix.iter()
+            .zip(rhs.matrix.iter())
+            .map(|(lhs_val, rhs_val)| *lhs_val + *rhs_val)
+            .collect();
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 }
 
@@ -111,19 +109,17 @@ where
 {
     type Output = Matrix<T>;
 
-    fn add(self, rhs: Matrix<T>) -> Self::Output {
+    fn add(self, mut rhs: Matrix<T>) -> Self::Output {
         if self.rows != rhs.rows || self.cols != rhs.cols {
             panic!("Differently sized matrices cannot be added together.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-        for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                params[row_idx].push(self.matrix[row_idx][col_idx] + rhs.matrix[row_idx][col_idx])
-            }
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (lhs_val, rhs_val) in self.matrix.iter().zip(rhs.matrix.iter_mut()) {
+            *rhs_val = *lhs_val + *rhs_val
         }
 
-        Matrix::from(params)
+        rhs
     }
 }
 
@@ -185,19 +181,10 @@ where
 {
     type Output = Matrix<T>;
 
-    fn add(self, rhs: &Matrix<T>) -> Self::Output {
-        if self.rows != rhs.rows || self.cols != rhs.cols {
-            panic!("Differently sized matrices cannot be added together.")
-        }
-
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-        for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                params[row_idx].push(self.matrix[row_idx][col_idx] + rhs.matrix[row_idx][col_idx])
-            }
-        }
+    fn add(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self += rhs;
 
-        Matrix::from(params)
+        self
     }
 }
 
@@ -259,18 +246,9 @@ where
 {
     type Output = Matrix<T>;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        if self.rows != rhs.rows || self.cols != rhs.cols {
-            panic!("Differently sized matrices cannot be added together.")
-        }
-
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-        for row_idx in 0..self.rows {
-            for col_idx in 0..self.cols {
-                params[row_idx].push(self.matrix[row_idx][col_idx] + rhs.matrix[row_idx][col_idx])
-            }
-        }
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
 
-        Matrix::from(params)
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,329 @@
+use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, MulAssign};
+use crate::traits::Ring;
+use crate::matrix_impl::Matrix;
+
+//
+//
+//          MATRIX += MATRIX IMPLEMENTATIONS
+//
+//
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// [Addition assignment][std::ops::AddAssign] implementation of 'Matrix<T> += &Matrix<T>'.
+/// 
+/// The left hand side matrix is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[1, 2],
+///                           [3, 4]];
+/// 
+/// let matrix2 = matrix![[5, 6],
+///                       [7, 8]];
+/// 
+/// matrix1 += &matrix2;
+/// 
+/// assert_eq!(matrix1, matrix![[6,  8],
+///                             [10, 12]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Add<Output = T> + Copy
+{
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be added together.")
+        }
+
+        for (lhs_val, rhs_val) in self.matrix.iter_mut().zip(rhs.matrix.iter()) {
+            *lhs_val = *lhs_val + *rhs_val
+        }
+    }
+}
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// [Addition assignment][std::ops::AddAssign] implementation of 'Matrix<T> += Matrix<T>'.
+/// 
+/// The left hand side matrix is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[1, 2],
+///                           [3, 4]];
+/// 
+/// let matrix2 = matrix![[5, 6],
+///                       [7, 8]];
+/// 
+/// // The result of '&matrix2 * 2' is an owned Matrix,
+/// // which is then added to 'matrix1'.
+/// matrix1 += &matrix2 * 2;
+/// 
+/// assert_eq!(matrix1, matrix![[11, 14],
+///                             [17, 20]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> AddAssign for Matrix<T>
+where
+    T: Add<Output = T> + Copy
+{
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs
+    }
+}
+
+
+//
+//
+//          MATRIX -= MATRIX IMPLEMENTATIONS
+//
+//
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// [Subtraction assignment][std::ops::SubAssign] implementation of 'Matrix<T> -= &Matrix<T>'.
+/// 
+/// The left hand side matrix is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[5, 6],
+///                           [7, 8]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4]];
+/// 
+/// matrix1 -= &matrix2;
+/// 
+/// assert_eq!(matrix1, matrix![[4, 4],
+///                             [4, 4]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be subtracted from each other.")
+        }
+
+        for (lhs_val, rhs_val) in self.matrix.iter_mut().zip(rhs.matrix.iter()) {
+            *lhs_val = *lhs_val - *rhs_val
+        }
+    }
+}
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// [Subtraction assignment][std::ops::SubAssign] implementation of 'Matrix<T> -= Matrix<T>'.
+/// 
+/// The left hand side matrix is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[5, 6],
+///                           [7, 8]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4]];
+/// 
+/// // The result of '&matrix2 * 2' is an owned Matrix,
+/// // which is then subtracted from 'matrix1'.
+/// matrix1 -= &matrix2 * 2;
+/// 
+/// assert_eq!(matrix1, matrix![[3, 2],
+///                             [1, 0]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> SubAssign for Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs
+    }
+}
+
+
+//
+//
+//          MATRIX *= MATRIX IMPLEMENTATIONS
+//
+//
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// [Multiplication assignment][std::ops::MulAssign] implementation of 'Matrix<T> *= &Matrix<T>'.
+/// 
+/// Since the right hand side matrix must be square, the shape of the left hand
+/// side matrix is preserved and its buffer is reused. Only a single row of
+/// scratch space is allocated.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[1, 2],
+///                           [3, 4],
+///                           [5, 6]];
+/// 
+/// let matrix2 = matrix![[1, 1],
+///                       [0, 2]];
+/// 
+/// matrix1 *= &matrix2;
+/// 
+/// assert_eq!(matrix1, matrix![[1, 5],
+///                             [3, 11],
+///                             [5, 17]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the right hand side [matrix][crate::matrix_impl::Matrix]
+/// is not square, or if its size is not equal to the number of columns in the left hand
+/// side [matrix][crate::matrix_impl::Matrix].
+impl<T> MulAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Ring
+{
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        if rhs.rows != rhs.cols || self.cols != rhs.rows {
+            panic!("The right matrix must be square and match the left matrix column count.")
+        }
+
+        let mut scratch = vec![T::zero(); self.cols];
+
+        for row_idx in 0..self.rows {
+            let row = &mut self.matrix[row_idx * self.cols..(row_idx + 1) * self.cols];
+
+            scratch.fill(T::zero());
+
+            for (index, lhs_value) in row.iter().enumerate() {
+                let rhs_row = &rhs.matrix[index * rhs.cols..(index + 1) * rhs.cols];
+
+                for (param, rhs_value) in scratch.iter_mut().zip(rhs_row) {
+                    *param += *lhs_value * *rhs_value
+                }
+            }
+
+            row.copy_from_slice(&scratch)
+        }
+    }
+}
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// [Multiplication assignment][std::ops::MulAssign] implementation of 'Matrix<T> *= Matrix<T>'.
+/// 
+/// Since the right hand side matrix must be square, the shape of the left hand
+/// side matrix is preserved and its buffer is reused.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix1 = matrix![[1, 2],
+///                           [3, 4]];
+/// 
+/// let matrix2 = matrix![[1, 0],
+///                       [0, 1]];
+/// 
+/// // The result of '&matrix2 * 2' is an owned Matrix.
+/// matrix1 *= &matrix2 * 2;
+/// 
+/// assert_eq!(matrix1, matrix![[2, 4],
+///                             [6, 8]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the right hand side [matrix][crate::matrix_impl::Matrix]
+/// is not square, or if its size is not equal to the number of columns in the left hand
+/// side [matrix][crate::matrix_impl::Matrix].
+impl<T> MulAssign for Matrix<T>
+where
+    T: Ring
+{
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self *= &rhs
+    }
+}
+
+
+//
+//
+//          MATRIX *= SCALAR IMPLEMENTATION
+//
+//
+/// [Multiplication assignment][std::ops::MulAssign] implementation of 'Matrix<T> *= T'.
+/// 
+/// Each element of the matrix is scaled in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix = matrix![[1, 2, 3],
+///                          [4, 5, 6]];
+/// 
+/// matrix *= 3;
+/// 
+/// assert_eq!(matrix, matrix![[3,  6,  9],
+///                            [12, 15, 18]]);
+/// ```
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: Copy + Mul<Output = T>
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for item in self.matrix.iter_mut() {
+            *item = rhs * *item
+        }
+    }
+}
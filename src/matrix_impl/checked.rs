@@ -0,0 +1,124 @@
+use std::ops::{Add, Sub};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError};
+use crate::traits::Ring;
+
+impl<T> Matrix<T> {
+    /// Adds two matrices together, returning a [ShapeError] instead of
+    /// panicking when the matrices are not equivalent in size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix1 = matrix![[1, 2],
+    ///                       [3, 4]];
+    /// 
+    /// let matrix2 = matrix![[5, 6],
+    ///                       [7, 8]];
+    /// 
+    /// let matrix3 = matrix![[1, 2, 3]];
+    /// 
+    /// assert_eq!(matrix1.checked_add(&matrix2), Ok(matrix![[6,  8],
+    ///                                                      [10, 12]]));
+    /// assert!(matrix1.checked_add(&matrix3).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, ShapeError>
+    where
+        T: Add<Output = T> + Copy
+    {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(ShapeError::new("add", Shape::Matrix(self.rows, self.cols), Shape::Matrix(other.rows, other.cols)))
+        }
+
+        Ok(self + other)
+    }
+
+    /// Subtracts one matrix from another, returning a [ShapeError] instead of
+    /// panicking when the matrices are not equivalent in size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix1 = matrix![[5, 6],
+    ///                       [7, 8]];
+    /// 
+    /// let matrix2 = matrix![[1, 2],
+    ///                       [3, 4]];
+    /// 
+    /// let matrix3 = matrix![[1, 2, 3]];
+    /// 
+    /// assert_eq!(matrix1.checked_sub(&matrix2), Ok(matrix![[4, 4],
+    ///                                                      [4, 4]]));
+    /// assert!(matrix1.checked_sub(&matrix3).is_err());
+    /// ```
+    pub fn checked_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, ShapeError>
+    where
+        T: Sub<Output = T> + Copy
+    {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(ShapeError::new("sub", Shape::Matrix(self.rows, self.cols), Shape::Matrix(other.rows, other.cols)))
+        }
+
+        Ok(self - other)
+    }
+
+    /// Multiplies two matrices together, returning a [ShapeError] instead of
+    /// panicking when the number of columns in the left hand side matrix is not
+    /// equal to the number of rows in the right hand side matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix1 = matrix![[1, 2],
+    ///                       [3, 4]];
+    /// 
+    /// let matrix2 = matrix![[5, 6, 7],
+    ///                       [8, 9, 10]];
+    /// 
+    /// assert_eq!(matrix1.checked_mul(&matrix2), Ok(matrix![[21, 24, 27],
+    ///                                                      [47, 54, 61]]));
+    /// assert!(matrix2.checked_mul(&matrix1).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, ShapeError>
+    where
+        T: Ring
+    {
+        if self.cols != other.rows {
+            return Err(ShapeError::new("mul", Shape::Matrix(self.rows, self.cols), Shape::Matrix(other.rows, other.cols)))
+        }
+
+        Ok(self * other)
+    }
+
+    /// Multiplies the matrix by a vector, returning a [ShapeError] instead of
+    /// panicking when the number of columns in the matrix is not equal to
+    /// the length of the vector.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[3, 4],
+    ///                      [5, 6]];
+    /// 
+    /// assert_eq!(matrix.checked_mul_vector(&vector![1, 2]), Ok(vector![11, 17]));
+    /// assert!(matrix.checked_mul_vector(&vector![1, 2, 3]).is_err());
+    /// ```
+    pub fn checked_mul_vector(&self, other: &Vector<T>) -> Result<Vector<T>, ShapeError>
+    where
+        T: Ring
+    {
+        if self.cols != other.len() {
+            return Err(ShapeError::new("mul", Shape::Matrix(self.rows, self.cols), Shape::Vector(other.len())))
+        }
+
+        Ok(self * other)
+    }
+}
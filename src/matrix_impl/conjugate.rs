@@ -0,0 +1,97 @@
+use crate::matrix_impl::Matrix;
+use crate::traits::{Zero, Real, ComplexField};
+
+impl<T> Matrix<T>
+where
+    T: ComplexField
+{
+    /// Returns the Matrix with each element replaced by its complex conjugate.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let matrix = matrix![[Complex::new(1.0, 2.0), Complex::new(3.0, 0.0)],
+    ///                      [Complex::new(0.0, -1.0), Complex::new(4.0, 5.0)]];
+    /// 
+    /// assert_eq!(matrix.conjugate(), matrix![[Complex::new(1.0, -2.0), Complex::new(3.0, 0.0)],
+    ///                                        [Complex::new(0.0,  1.0), Complex::new(4.0, -5.0)]]);
+    /// ```
+    pub fn conjugate(&self) -> Matrix<T> {
+        self.lambda(|val| val.conjugate())
+    }
+
+    /// Returns the adjoint, or conjugate transpose, `Aᴴ` of the matrix.
+    /// 
+    /// For real matrices this is the [transpose][Matrix::transpose].
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let matrix = matrix![[Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)],
+    ///                      [Complex::new(0.0, 3.0), Complex::new(4.0, -1.0)]];
+    /// 
+    /// assert_eq!(matrix.adjoint(), matrix![[Complex::new(1.0, -1.0), Complex::new(0.0, -3.0)],
+    ///                                      [Complex::new(2.0,  0.0), Complex::new(4.0,  1.0)]]);
+    /// ```
+    pub fn adjoint(&self) -> Matrix<T> {
+        self.transpose().lambda(|val| val.conjugate())
+    }
+
+    /// Returns the conjugate transpose `Aᴴ` of the matrix.
+    /// 
+    /// This is another name for [adjoint][Matrix::adjoint].
+    pub fn conjugate_transpose(&self) -> Matrix<T> {
+        self.adjoint()
+    }
+
+    /// Returns true if the matrix is square and equal to its own
+    /// [adjoint][Matrix::adjoint], up to rounding.
+    /// 
+    /// Elements are compared with a tolerance of `ε * n * max|A|`.
+    /// For real matrices this checks symmetry.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let hermitian = matrix![[Complex::new(2.0, 0.0),  Complex::new(1.0, -1.0)],
+    ///                         [Complex::new(1.0, 1.0),  Complex::new(3.0,  0.0)]];
+    /// 
+    /// let symmetric = matrix![[Complex::new(2.0, 0.0),  Complex::new(1.0, 1.0)],
+    ///                         [Complex::new(1.0, 1.0),  Complex::new(3.0, 0.0)]];
+    /// 
+    /// assert!(hermitian.is_hermitian());
+    /// assert!(!symmetric.is_hermitian());
+    /// ```
+    pub fn is_hermitian(&self) -> bool {
+        if self.rows() != self.cols() {
+            return false
+        }
+
+        let size = self.rows();
+
+        let mut max_abs = T::Real::zero();
+        for val in self.iter() {
+            if val.modulus() > max_abs {
+                max_abs = val.modulus()
+            }
+        }
+
+        let tolerance = T::Real::epsilon() * T::Real::from_f64(size as f64) * max_abs;
+
+        for row in 0..size {
+            for col in row..size {
+                if (self[(row, col)] - self[(col, row)].conjugate()).modulus() > tolerance {
+                    return false
+                }
+            }
+        }
+
+        true
+    }
+}
@@ -0,0 +1,199 @@
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::traits::{Zero, One};
+
+impl<T> Matrix<T> {
+    /// Constructs a Matrix with the given rows and columns filled with zeros.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix: Matrix<i32> = Matrix::zeros(2, 3);
+    /// 
+    /// assert_eq!(matrix, matrix![[0, 0, 0],
+    ///                            [0, 0, 0]]);
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T>
+    where
+        T: Zero + Clone
+    {
+        Matrix::from_parts(rows, cols, vec![T::zero(); rows * cols])
+    }
+
+    /// Constructs a Matrix with the given rows and columns filled with ones.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix: Matrix<i32> = Matrix::ones(3, 2);
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 1],
+    ///                            [1, 1],
+    ///                            [1, 1]]);
+    /// ```
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T>
+    where
+        T: One + Clone
+    {
+        Matrix::from_parts(rows, cols, vec![T::one(); rows * cols])
+    }
+
+    /// Constructs the square identity Matrix of the given size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix: Matrix<i32> = Matrix::identity(3);
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 0, 0],
+    ///                            [0, 1, 0],
+    ///                            [0, 0, 1]]);
+    /// ```
+    pub fn identity(size: usize) -> Matrix<T>
+    where
+        T: Zero + One + Clone
+    {
+        let mut matrix = vec![T::zero(); size * size];
+
+        for idx in 0..size {
+            matrix[idx * size + idx] = T::one()
+        }
+
+        Matrix::from_parts(size, size, matrix)
+    }
+
+    /// Constructs a Matrix with the given rows and columns, computing
+    /// each element from its row index and column index.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = Matrix::from_fn(2, 3, |row, col| row * 3 + col);
+    /// 
+    /// assert_eq!(matrix, matrix![[0, 1, 2],
+    ///                            [3, 4, 5]]);
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut funct: F) -> Matrix<T>
+    where
+        F: FnMut(usize, usize) -> T
+    {
+        let mut matrix = Vec::with_capacity(rows * cols);
+
+        for row_idx in 0..rows {
+            for col_idx in 0..cols {
+                matrix.push(funct(row_idx, col_idx))
+            }
+        }
+
+        Matrix::from_parts(rows, cols, matrix)
+    }
+
+    /// Constructs a square Matrix with the elements of the Vector
+    /// along its diagonal and zeros elsewhere.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = Matrix::from_diagonal(&vector![1, 2, 3]);
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 0, 0],
+    ///                            [0, 2, 0],
+    ///                            [0, 0, 3]]);
+    /// ```
+    pub fn from_diagonal(diagonal: &Vector<T>) -> Matrix<T>
+    where
+        T: Zero + Clone
+    {
+        let size = diagonal.len();
+        let mut matrix = vec![T::zero(); size * size];
+
+        for (idx, val) in diagonal.iter().enumerate() {
+            matrix[idx * size + idx] = val.clone()
+        }
+
+        Matrix::from_parts(size, size, matrix)
+    }
+
+    /// Constructs a Matrix whose rows are the given Vectors.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = Matrix::from_rows(vec![vector![1, 2, 3],
+    ///                                     vector![4, 5, 6]]);
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 2, 3],
+    ///                            [4, 5, 6]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the Vectors are not all the same length.
+    pub fn from_rows(rows: Vec<Vector<T>>) -> Matrix<T> {
+        let row_count = rows.len();
+        let cols = rows.first().map_or(0, |row| row.len());
+
+        let mut matrix = Vec::with_capacity(row_count * cols);
+
+        for row in rows {
+            if row.len() != cols {
+                panic!("Input Vectors do not have same length for all rows")
+            }
+
+            matrix.extend(row)
+        }
+
+        Matrix::from_parts(row_count, cols, matrix)
+    }
+
+    /// Constructs a Matrix whose columns are the given Vectors.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = Matrix::from_cols(vec![vector![1, 2, 3],
+    ///                                     vector![4, 5, 6]]);
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 4],
+    ///                            [2, 5],
+    ///                            [3, 6]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the Vectors are not all the same length.
+    pub fn from_cols(cols: Vec<Vector<T>>) -> Matrix<T> {
+        let col_count = cols.len();
+        let rows = cols.first().map_or(0, |col| col.len());
+
+        let mut iters = Vec::with_capacity(col_count);
+
+        for col in cols {
+            if col.len() != rows {
+                panic!("Input Vectors do not have same length for all columns")
+            }
+
+            iters.push(col.into_iter())
+        }
+
+        let mut matrix = Vec::with_capacity(rows * col_count);
+
+        for _ in 0..rows {
+            for col in iters.iter_mut() {
+                // Every column was checked to hold exactly 'rows' elements.
+                matrix.extend(col.next())
+            }
+        }
+
+        Matrix::from_parts(rows, col_count, matrix)
+    }
+}
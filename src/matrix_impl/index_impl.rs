@@ -0,0 +1,196 @@
+use std::ops::{Index, IndexMut};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+
+impl<T> Matrix<T> {
+    /// Borrows the element at the given row and column, or returns [None]
+    /// if either index is out of range.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// assert_eq!(matrix.get(1, 0), Some(&4));
+    /// assert_eq!(matrix.get(0, 3), None);
+    /// assert_eq!(matrix.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        self.matrix.get(row * self.cols + col)
+    }
+
+    /// Mutably borrows the element at the given row and column, or returns [None]
+    /// if either index is out of range.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let mut matrix = matrix![[1, 2],
+    ///                          [3, 4]];
+    /// 
+    /// if let Some(val) = matrix.get_mut(0, 1) {
+    ///     *val = 7
+    /// }
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 7],
+    ///                            [3, 4]]);
+    /// assert_eq!(matrix.get_mut(0, 2), None);
+    /// ```
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        self.matrix.get_mut(row * self.cols + col)
+    }
+
+    /// Borrows a row of the matrix as a slice.
+    /// 
+    /// Since the matrix is stored in row-major order, this does not copy.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// assert_eq!(matrix.row(1), &[4, 5, 6]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range.
+    pub fn row(&self, row: usize) -> &[T] {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        &self.matrix[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Mutably borrows a row of the matrix as a slice.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let mut matrix = matrix![[1, 2, 3],
+    ///                          [4, 5, 6]];
+    /// 
+    /// matrix.row_mut(0).swap(0, 2);
+    /// 
+    /// assert_eq!(matrix, matrix![[3, 2, 1],
+    ///                            [4, 5, 6]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        &mut self.matrix[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Copies a column of the matrix into a Vector.
+    /// 
+    /// Columns are not contiguous in memory, so unlike [row][Matrix::row]
+    /// the elements must be cloned.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// assert_eq!(matrix.col(1), vector![2, 5]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the column is out of range.
+    pub fn col(&self, col: usize) -> Vector<T>
+    where
+        T: Clone
+    {
+        if col >= self.cols {
+            panic!("Column index out of range for the matrix.")
+        }
+
+        let mut params = Vec::with_capacity(self.rows);
+
+        for row_idx in 0..self.rows {
+            params.push(self.matrix[row_idx * self.cols + col].clone())
+        }
+
+        Vector::from(params)
+    }
+}
+
+/// The [indexing][std::ops::Index] implementation for 'Matrix\[(row, col)\]'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix = matrix![[1, 2, 3],
+///                      [4, 5, 6]];
+/// 
+/// assert_eq!(matrix[(0, 2)], 3);
+/// assert_eq!(matrix[(1, 0)], 4);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if either the row or column is out of range.
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        if row >= self.rows || col >= self.cols {
+            panic!("Index out of range for the matrix.")
+        }
+
+        &self.matrix[row * self.cols + col]
+    }
+}
+
+/// The [mutable indexing][std::ops::IndexMut] implementation for 'Matrix\[(row, col)\]'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix = matrix![[1, 2],
+///                          [3, 4]];
+/// 
+/// matrix[(1, 1)] = 9;
+/// 
+/// assert_eq!(matrix, matrix![[1, 2],
+///                            [3, 9]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if either the row or column is out of range.
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        if row >= self.rows || col >= self.cols {
+            panic!("Index out of range for the matrix.")
+        }
+
+        &mut self.matrix[row * self.cols + col]
+    }
+}
@@ -0,0 +1,103 @@
+use crate::matrix_impl::Matrix;
+use crate::decomposition::jacobi_svd;
+use crate::error::LinalgError;
+use crate::traits::Real;
+
+impl<T> Matrix<T>
+where
+    T: Real
+{
+    /// Computes the inverse of a square matrix through its [LU][crate::decomposition::LU] decomposition.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[2.0, 1.0],
+    ///                      [4.0, 3.0]];
+    /// 
+    /// let inverse = matrix.inverse().unwrap();
+    /// 
+    /// assert_eq!(inverse, matrix![[ 1.5, -0.5],
+    ///                             [-2.0,  1.0]]);
+    /// assert_eq!(inverse * &matrix, Matrix::identity(2));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square, or
+    /// [LinalgError::Singular] if the matrix is singular.
+    pub fn inverse(&self) -> Result<Matrix<T>, LinalgError> {
+        self.lu()?.solve_matrix(&Matrix::identity(self.rows()))
+    }
+
+    /// Computes the Moore–Penrose pseudo-inverse of a matrix of any shape.
+    /// 
+    /// The pseudo-inverse is built from the singular value decomposition,
+    /// where singular values at or below `tolerance` are treated as zero.
+    /// A common choice of tolerance is `ε * max(rows, cols) * σ_max`.
+    /// 
+    /// For a square, non-singular matrix this agrees with [inverse][Matrix::inverse].
+    /// For a tall matrix with independent columns, `pseudo_inverse() * b` is the
+    /// least-squares solution of `A * x = b`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1.0, 0.0],
+    ///                      [0.0, 2.0],
+    ///                      [0.0, 0.0]];
+    /// 
+    /// let pseudo_inverse = matrix.pseudo_inverse(1e-12).unwrap();
+    /// 
+    /// assert_eq!(pseudo_inverse, matrix![[1.0, 0.0, 0.0],
+    ///                                    [0.0, 0.5, 0.0]]);
+    /// ```
+    /// Rank-deficient matrices are supported.
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: Matrix<f64> = matrix![[1.0, 1.0],
+    ///                                   [1.0, 1.0]];
+    /// 
+    /// let pseudo_inverse = matrix.pseudo_inverse(1e-12).unwrap();
+    /// 
+    /// for val in pseudo_inverse.iter() {
+    ///     assert!((val - 0.25).abs() < 1e-12)
+    /// }
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NoConvergence] if the underlying
+    /// [singular value decomposition][Matrix::svd] does not converge.
+    pub fn pseudo_inverse(&self, tolerance: T) -> Result<Matrix<T>, LinalgError> {
+        // The decomposition works on matrices with at least as many rows
+        // as columns, and the pseudo-inverse commutes with transposition.
+        if self.rows() < self.cols() {
+            return Ok(self.transpose().pseudo_inverse(tolerance)?.into_transpose())
+        }
+
+        let (u, sigma, v) = jacobi_svd(self)?;
+
+        // A⁺ = V * Σ⁺ * Uᵀ, skipping the singular values treated as zero.
+        let mut params = Matrix::zeros(self.cols(), self.rows());
+
+        for (idx, value) in sigma.iter().enumerate() {
+            if *value <= tolerance {
+                continue
+            }
+
+            let reciprocal = T::one() / *value;
+
+            for row in 0..self.cols() {
+                let scaled = v[(row, idx)] * reciprocal;
+
+                for col in 0..self.rows() {
+                    params[(row, col)] += scaled * u[(col, idx)]
+                }
+            }
+        }
+
+        Ok(params)
+    }
+}
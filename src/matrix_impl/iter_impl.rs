@@ -0,0 +1,247 @@
+use std::iter::StepBy;
+use std::ops::Range;
+use crate::matrix_impl::Matrix;
+
+impl<T> Matrix<T> {
+    /// Returns an iterator over the elements of the matrix in row-major order.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4]];
+    /// 
+    /// let elements: Vec<&i32> = matrix.iter().collect();
+    /// 
+    /// assert_eq!(elements, vec![&1, &2, &3, &4]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.matrix.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the matrix,
+    /// in row-major order.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let mut matrix = matrix![[1, 2],
+    ///                          [3, 4]];
+    /// 
+    /// for val in matrix.iter_mut() {
+    ///     *val *= 10
+    /// }
+    /// 
+    /// assert_eq!(matrix, matrix![[10, 20],
+    ///                            [30, 40]]);
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.matrix.iter_mut()
+    }
+
+    /// Returns an iterator over the rows of the matrix, each
+    /// borrowed as a slice.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// let row_sums: Vec<i32> = matrix.row_iter()
+    ///     .map(|row| row.iter().sum())
+    ///     .collect();
+    /// 
+    /// assert_eq!(row_sums, vec![6, 15]);
+    /// ```
+    pub fn row_iter(&self) -> RowIter<'_, T> {
+        RowIter {
+            matrix : &self.matrix,
+            cols : self.cols,
+            range : 0..self.rows
+        }
+    }
+
+    /// Returns an iterator over the columns of the matrix.
+    /// 
+    /// Each column is yielded as a strided iterator over the borrowed
+    /// elements, so no elements are copied.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// let col_sums: Vec<i32> = matrix.col_iter()
+    ///     .map(|col| col.sum())
+    ///     .collect();
+    /// 
+    /// assert_eq!(col_sums, vec![5, 7, 9]);
+    /// ```
+    pub fn col_iter(&self) -> ColIter<'_, T> {
+        ColIter {
+            matrix : &self.matrix,
+            cols : self.cols,
+            range : 0..self.cols
+        }
+    }
+}
+
+/// An iterator over the rows of a [Matrix], created by [Matrix::row_iter].
+#[derive(Debug, Clone)]
+pub struct RowIter<'a, T> {
+    matrix : &'a [T],
+    cols : usize,
+    range : Range<usize>
+}
+
+impl<'a, T> RowIter<'a, T> {
+    fn row(&self, row: usize) -> &'a [T] {
+        &self.matrix[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<'a, T> Iterator for RowIter<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|row| self.row(row))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|row| self.row(row))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowIter<'a, T> {}
+
+/// An iterator over the columns of a [Matrix], created by [Matrix::col_iter].
+/// 
+/// Each column is a strided iterator over the borrowed elements of that column.
+#[derive(Debug, Clone)]
+pub struct ColIter<'a, T> {
+    matrix : &'a [T],
+    cols : usize,
+    range : Range<usize>
+}
+
+impl<'a, T> ColIter<'a, T> {
+    fn col(&self, col: usize) -> StepBy<std::slice::Iter<'a, T>> {
+        // A matrix without rows has an empty buffer, so the
+        // column offset may lie beyond its end.
+        self.matrix.get(col..)
+            .unwrap_or(&[])
+            .iter()
+            .step_by(self.cols)
+    }
+}
+
+impl<'a, T> Iterator for ColIter<'a, T> {
+    type Item = StepBy<std::slice::Iter<'a, T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|col| self.col(col))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|col| self.col(col))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColIter<'a, T> {}
+
+/// Consumes the matrix into an iterator over its elements in row-major order.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix = matrix![[1, 2],
+///                      [3, 4]];
+/// 
+/// let elements: Vec<i32> = matrix.into_iter().collect();
+/// 
+/// assert_eq!(elements, vec![1, 2, 3, 4]);
+/// ```
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.into_iter()
+    }
+}
+
+/// Iterates over borrowed elements of the matrix in row-major order.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix = matrix![[1, 2],
+///                      [3, 4]];
+/// let mut total = 0;
+/// 
+/// for val in &matrix {
+///     total += val
+/// }
+/// 
+/// assert_eq!(total, 10);
+/// ```
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.iter()
+    }
+}
+
+/// Iterates over mutably borrowed elements of the matrix in row-major order.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let mut matrix = matrix![[1, 2],
+///                          [3, 4]];
+/// 
+/// for val in &mut matrix {
+///     *val -= 1
+/// }
+/// 
+/// assert_eq!(matrix, matrix![[0, 1],
+///                            [2, 3]]);
+/// ```
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.matrix.iter_mut()
+    }
+}
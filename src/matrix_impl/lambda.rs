@@ -21,20 +21,11 @@ impl<T> Matrix<T> {
     where
         F: Fn(&T) -> T
     {
-        let mut params = Vec::with_capacity(self.rows);
-        
-        
-        for row in &self.matrix {
-            let mut new_row = Vec::with_capacity(self.cols);
-            
-            for col in row {
-                new_row.push(funct(col))
-            }
-            
-            params.push(new_row)
-        }
+        let params = self.matrix.iter()
+            .map(funct)
+            .collect();
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 
     /// Applies a function dependent on location
@@ -57,20 +48,15 @@ impl<T> Matrix<T> {
     where
         F: Fn(usize, usize) -> T
     {
-        let mut params = Vec::with_capacity(self.rows);
-        
+        let mut params = Vec::with_capacity(self.rows * self.cols);
         
         for row_idx in 0..self.rows {
-            let mut new_row = Vec::with_capacity(self.cols);
-            
             for col_idx in 0..self.cols {
-                new_row.push(funct(row_idx, col_idx))
+                params.push(funct(row_idx, col_idx))
             }
-
-            params.push(new_row)
         }
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 
     /// Applies a function dependent on location and value
@@ -93,18 +79,11 @@ impl<T> Matrix<T> {
     where
         F: Fn(usize, usize, &T) -> T
     {
-        let mut params = Vec::with_capacity(self.rows);
-
-        for (row_idx, row) in self.matrix.iter().enumerate() {
-            let mut new_row = Vec::with_capacity(self.cols);
-
-            for (col_idx, col) in row.iter().enumerate() {
-                new_row.push(funct(row_idx, col_idx, col))
-            }
-            
-            params.push(new_row)
-        }
+        let params = self.matrix.iter()
+            .enumerate()
+            .map(|(idx, val)| funct(idx / self.cols, idx % self.cols, val))
+            .collect();
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 }
\ No newline at end of file
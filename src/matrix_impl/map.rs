@@ -1,4 +1,5 @@
 use crate::matrix_impl::Matrix;
+use crate::error::{Shape, ShapeError};
 
 impl<T> Matrix<T> {
     /// Applies a function dependent on value 
@@ -33,19 +34,44 @@ impl<T> Matrix<T> {
             panic!("Cannot map matrices of different sizes.")
         }
 
-        let mut params = Vec::with_capacity(self.rows);
+        let params = self.matrix.iter()
+            .zip(other.matrix.iter())
+            .map(|(lhs_val, rhs_val)| funct(lhs_val, rhs_val))
+            .collect();
 
-        for row_idx in 0..self.rows {
-            let mut new_row = Vec::with_capacity(self.cols);
-
-            for col_idx in 0..self.cols {
-                new_row.push(funct(&self.matrix[row_idx][col_idx], &other.matrix[row_idx][col_idx]))
-            }
+        Matrix::from_parts(self.rows, self.cols, params)
+    }
 
-            params.push(new_row)
+    /// Applies a function dependent on value to each corresponding
+    /// element between the two matrices, returning a [ShapeError]
+    /// instead of panicking when the matrices are not identically sized.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix1 = matrix![[1, 2],
+    ///                       [3, 4]];
+    /// 
+    /// let matrix2 = matrix![[5, 6],
+    ///                       [7, 8]];
+    /// 
+    /// let matrix3 = matrix![[5, 6]];
+    /// 
+    /// assert_eq!(matrix1.try_map(&matrix2, |val1, val2| val1 * val2), Ok(matrix![[5,  12],
+    ///                                                                           [21, 32]]));
+    /// assert!(matrix1.try_map(&matrix3, |val1, val2| val1 * val2).is_err());
+    /// ```
+    pub fn try_map<F>(&self, other: &Matrix<T>, funct: F) -> Result<Matrix<T>, ShapeError>
+    where
+        F: Fn(&T, &T) -> T
+    {
+        if (self.rows != other.rows) || (self.cols != other.cols) {
+            return Err(ShapeError::new("map", Shape::Matrix(self.rows, self.cols), Shape::Matrix(other.rows, other.cols)))
         }
 
-        Matrix::from(params)
+        Ok(self.map(other, funct))
     }
 
     /*
@@ -99,18 +125,12 @@ impl<T> Matrix<T> {
             panic!("Cannot map matrices of different sizes.")
         }
 
-        let mut params = Vec::with_capacity(self.rows);
-
-        for row_idx in 0..self.rows {
-            let mut new_row = Vec::with_capacity(self.cols);
-
-            for col_idx in 0..self.cols {
-                new_row.push(funct(row_idx, col_idx, &self.matrix[row_idx][col_idx], &other.matrix[row_idx][col_idx]))
-            }
-
-            params.push(new_row)
-        }
+        let params = self.matrix.iter()
+            .zip(other.matrix.iter())
+            .enumerate()
+            .map(|(idx, (lhs_val, rhs_val))| funct(idx / self.cols, idx % self.cols, lhs_val, rhs_val))
+            .collect();
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 }
\ No newline at end of file
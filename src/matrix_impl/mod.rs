@@ -1,26 +1,62 @@
 mod mul_impl;
 mod add_impl;
+mod sub_impl;
+mod neg_impl;
+mod assign_impl;
 mod lambda;
 mod map;
+mod checked;
+mod index_impl;
+mod iter_impl;
+mod constructors;
+mod transpose;
+mod inverse;
+mod view;
+mod stack;
+mod conjugate;
+
+pub use crate::matrix_impl::iter_impl::{RowIter, ColIter};
+pub use crate::matrix_impl::view::{MatrixView, MatrixViewMut};
 
 use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError};
 
-#[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 /// The Matrix type.
+/// 
+/// The elements are stored in a single contiguous buffer in row-major
+/// order, so the element at row `r` and column `c` lives at `r * cols + c`.
 pub struct Matrix<T>
 {
     rows : usize,
     cols : usize,
-    matrix : Vec<Vec<T>>
+    matrix : Vec<T>
 }
 
-impl<T> Matrix<T>
-where
-    T: Copy
-{
+impl<T> Matrix<T> {
+    /// Builds a Matrix directly from a row-major buffer.
+    /// 
+    /// The caller guarantees that `matrix.len() == rows * cols`.
+    pub(crate) fn from_parts(rows: usize, cols: usize, matrix: Vec<T>) -> Matrix<T> {
+        debug_assert_eq!(matrix.len(), rows * cols);
+
+        Matrix {
+            rows,
+            cols,
+            matrix
+        }
+    }
+
+    /// Consumes the Matrix and returns its row-major buffer.
+    pub(crate) fn into_parts(self) -> Vec<T> {
+        self.matrix
+    }
+
     /// Converts a single dimentional Matrix into a Vector, consuming the Matrix.
     /// 
+    /// Since the elements are already stored contiguously, the internal buffer
+    /// is moved into the Vector without copying.
+    /// 
     /// # Example
     /// ```
     /// use simp_linalg::prelude::*;
@@ -54,33 +90,95 @@ where
     /// // let vector3 = both_matrix.into_vector();
     /// ```
     pub fn into_vector(self) -> Vector<T> {
-        if self.rows == 1 {
-            let mut params = Vec::with_capacity(self.cols);
+        if self.rows != 1 && self.cols != 1 {
+            panic!("Cannot convert matrix because neither rows nor columns are 1")
+        }
 
-            for col in &self.matrix[0] {
-                params.push(*col)
-            }
+        Vector::from(self.matrix)
+    }
 
-            return Vector::from(params)
+    /// Converts a single dimentional Matrix into a Vector, consuming the Matrix.
+    /// 
+    /// Unlike [into_vector][Matrix::into_vector], this returns a [ShapeError]
+    /// instead of panicking when neither rows nor columns are equal to 1.
+    /// 
+    /// The error records the shape of the matrix and the single row
+    /// shape it was expected to have.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::error::Shape;
+    /// 
+    /// let col_matrix = matrix![[1],
+    ///                          [2]];
+    /// 
+    /// let both_matrix = matrix![[1,2],
+    ///                           [3,4]];
+    /// 
+    /// assert_eq!(col_matrix.try_into_vector(), Ok(vector![1, 2]));
+    /// 
+    /// let error = both_matrix.try_into_vector().unwrap_err();
+    /// 
+    /// assert_eq!(error.lhs(), Shape::Matrix(2, 2));
+    /// assert_eq!(error.rhs(), Shape::Matrix(1, 2));
+    /// ```
+    pub fn try_into_vector(self) -> Result<Vector<T>, ShapeError> {
+        if self.rows != 1 && self.cols != 1 {
+            return Err(ShapeError::new("into_vector", Shape::Matrix(self.rows, self.cols), Shape::Matrix(1, self.cols)))
         }
-        
-        else if self.cols == 1 {
-            let mut params: Vec<T> = Vec::with_capacity(self.rows);
 
-            for row in self.matrix {
-                params.push(row[0])
+        Ok(Vector::from(self.matrix))
+    }
+
+    /// Converts a 2D [Vec][std::vec::Vec] into a Matrix, returning a
+    /// [ShapeError] instead of panicking when the rows differ in length.
+    /// 
+    /// The error records the length of the first row and the length of the
+    /// first row that disagrees with it.
+    /// 
+    /// This is the fallible counterpart to the [From] implementation. A
+    /// [TryFrom] implementation cannot be provided alongside it, since the
+    /// standard library already derives one from [From].
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::error::Shape;
+    /// 
+    /// let matrix = Matrix::try_from_vec(vec![vec![1, 2],
+    ///                                        vec![3, 4]]);
+    /// 
+    /// assert_eq!(matrix, Ok(matrix![[1, 2],
+    ///                               [3, 4]]));
+    /// 
+    /// let error = Matrix::try_from_vec(vec![vec![1, 2],
+    ///                                       vec![3]]).unwrap_err();
+    /// 
+    /// assert_eq!(error.lhs(), Shape::Vector(2));
+    /// assert_eq!(error.rhs(), Shape::Vector(1));
+    /// ```
+    pub fn try_from_vec(params: Vec<Vec<T>>) -> Result<Matrix<T>, ShapeError> {
+        let rows = params.len();
+        let cols = params.first().map_or(0, |row| row.len());
+
+        let mut matrix = Vec::with_capacity(rows * cols);
+
+        for row in params {
+            if row.len() != cols {
+                return Err(ShapeError::new("from", Shape::Vector(cols), Shape::Vector(row.len())))
             }
 
-            return Vector::from(params);
-        }
-        
-        else {
-            panic!("Cannot convert matrix because neither rows nor columns are 1")
+            matrix.extend(row)
         }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            matrix
+        })
     }
-}
 
-impl<T> Matrix<T> {
     /// Returns the number of rows of the Matrix<T>.
     pub fn rows(&self) -> usize {
         self.rows
@@ -91,12 +189,66 @@ impl<T> Matrix<T> {
         self.cols
     }
 
-    /// Unwraps the matrix.
+    /// Borrows the elements of the Matrix<T> as a row-major slice.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4]];
+    /// 
+    /// assert_eq!(matrix.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.matrix
+    }
+
+    /// Mutably borrows the elements of the Matrix<T> as a row-major slice.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let mut matrix = matrix![[1, 2],
+    ///                          [3, 4]];
+    /// 
+    /// matrix.as_mut_slice()[1] = 7;
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 7],
+    ///                            [3, 4]]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.matrix
+    }
+
+    /// Unwraps the matrix into its rows.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4]];
+    /// 
+    /// assert_eq!(matrix.into_inner(), vec![vec![1, 2],
+    ///                                      vec![3, 4]]);
+    /// ```
     pub fn into_inner(self) -> Vec<Vec<T>> {
-        self.matrix
+        let mut params = Vec::with_capacity(self.rows);
+        let mut elements = self.matrix.into_iter();
+
+        for _ in 0..self.rows {
+            params.push(elements.by_ref().take(self.cols).collect())
+        }
+
+        params
     }
 }
 
+/// Converts a 2D [Vec][std::vec::Vec] into a Matrix, flattening
+/// the rows into a single row-major buffer.
+/// 
 /// # Panic!
 /// 
 /// This function will panic if there exists a differently sized internal [vec][std::vec::Vec].
@@ -114,20 +266,9 @@ impl<T> Matrix<T> {
 /// ```
 impl<T> From<Vec<Vec<T>>> for Matrix<T> {
     fn from(params: Vec<Vec<T>>) -> Self {
-
-        let rows = params.len();
-        let cols = params[0].len();
-
-        for row in 1..rows {
-            if params[row].len() != cols {
-                panic!("Input 2D Vec does not have same length for all rows")
-            }
-        }
-
-        Matrix {
-            rows : params.len(),
-            cols : params[0].len(),
-            matrix : params
+        match Matrix::try_from_vec(params) {
+            Ok(matrix) => matrix,
+            Err(_) => panic!("Input 2D Vec does not have same length for all rows")
         }
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
-use std::ops::{Mul, AddAssign};
+use std::ops::Mul;
 use crate::prelude::*;
+use crate::traits::Ring;
 
 //
 //
@@ -42,7 +43,7 @@ use crate::prelude::*;
 /// in the right hand side [matrix][crate::matrix_impl::Matrix].
 impl<T> Mul for &Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Matrix<T>;
 
@@ -51,42 +52,25 @@ where
             panic!("The left matrix row count is not equal to the right matrix column count.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-
-        for out_row_index in 0..self.rows {
-            for out_col_index in 0..rhs.cols {
-                let mut param = T::default();
-                
-                // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-                for index in 0..self.cols {
-                    //get the data from the lhs's row
-                    let lhs_row_ptr = self.matrix[out_row_index].as_ptr();
-                    let lhs_value: T;
-
-                    // SAFTEY: self.cols is guarenteed to be one-greater-than
-                    //         the last index for the lhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        lhs_value = *lhs_row_ptr.add(index)
-                    }
-
-                    //get the data from the rhs's col
-                    let rhs_row_ptr = rhs.matrix[index].as_ptr();
-                    let rhs_value: T;
-
-                    // SAFETY: rhs.rows is guarenteed to be one-greater-than
-                    //         the last index in the rhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        rhs_value = *rhs_row_ptr.add(out_col_index)
-                    }
-
-                    param += lhs_value * rhs_value
+        let mut params = vec![T::zero(); self.rows * rhs.cols];
+
+        // The product is accumulated in row-by-row (i-k-j) order so that
+        // both the lhs row and the rhs rows are walked contiguously
+        // through memory, instead of striding down the rhs columns.
+        for row_idx in 0..self.rows {
+            let out_row = &mut params[row_idx * rhs.cols..(row_idx + 1) * rhs.cols];
+
+            for index in 0..self.cols {
+                let lhs_value = self.matrix[row_idx * self.cols + index];
+                let rhs_row = &rhs.matrix[index * rhs.cols..(index + 1) * rhs.cols];
+
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs_row) {
+                    *param += lhs_value * *rhs_value
                 }
-                params[out_row_index].push(param)
             }
         }
-        Matrix::from(params)
+
+        Matrix::from_parts(self.rows, rhs.cols, params)
     }
 }
 
@@ -145,7 +129,7 @@ where
 /// in the right hand side [matrix][crate::matrix_impl::Matrix].
 impl<T> Mul<Matrix<T>> for &Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Matrix<T>;
 
@@ -154,42 +138,25 @@ where
             panic!("The left matrix row count is not equal to the right matrix column count.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-
-        for out_row_index in 0..self.rows {
-            for out_col_index in 0..rhs.cols {
-                let mut param = T::default();
-                
-                // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-                for index in 0..self.cols {
-                    //get the data from the lhs's row
-                    let lhs_row_ptr = self.matrix[out_row_index].as_ptr();
-                    let lhs_value: T;
-
-                    // SAFTEY: self.cols is guarenteed to be one-greater-than
-                    //         the last index for the lhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        lhs_value = *lhs_row_ptr.add(index)
-                    }
-
-                    //get the data from the rhs's col
-                    let rhs_row_ptr = rhs.matrix[index].as_ptr();
-                    let rhs_value: T;
-
-                    // SAFETY: rhs.rows is guarenteed to be one-greater-than
-                    //         the last index in the rhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        rhs_value = *rhs_row_ptr.add(out_col_index)
-                    }
-
-                    param += lhs_value * rhs_value
+        let mut params = vec![T::zero(); self.rows * rhs.cols];
+
+        // The product is accumulated in row-by-row (i-k-j) order so that
+        // both the lhs row and the rhs rows are walked contiguously
+        // through memory, instead of striding down the rhs columns.
+        for row_idx in 0..self.rows {
+            let out_row = &mut params[row_idx * rhs.cols..(row_idx + 1) * rhs.cols];
+
+            for index in 0..self.cols {
+                let lhs_value = self.matrix[row_idx * self.cols + index];
+                let rhs_row = &rhs.matrix[index * rhs.cols..(index + 1) * rhs.cols];
+
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs_row) {
+                    *param += lhs_value * *rhs_value
                 }
-                params[out_row_index].push(param)
             }
         }
-        Matrix::from(params)
+
+        Matrix::from_parts(self.rows, rhs.cols, params)
     }
 }
 
@@ -248,7 +215,7 @@ where
 /// in the right hand side [matrix][crate::matrix_impl::Matrix].
 impl<T> Mul<&Matrix<T>> for Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Matrix<T>;
 
@@ -257,42 +224,25 @@ where
             panic!("The left matrix row count is not equal to the right matrix column count.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-
-        for out_row_index in 0..self.rows {
-            for out_col_index in 0..rhs.cols {
-                let mut param = T::default();
-                
-                // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-                for index in 0..self.cols {
-                    //get the data from the lhs's row
-                    let lhs_row_ptr = self.matrix[out_row_index].as_ptr();
-                    let lhs_value: T;
-
-                    // SAFTEY: self.cols is guarenteed to be one-greater-than
-                    //         the last index for the lhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        lhs_value = *lhs_row_ptr.add(index)
-                    }
-
-                    //get the data from the rhs's col
-                    let rhs_row_ptr = rhs.matrix[index].as_ptr();
-                    let rhs_value: T;
-
-                    // SAFETY: rhs.rows is guarenteed to be one-greater-than
-                    //         the last index in the rhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        rhs_value = *rhs_row_ptr.add(out_col_index)
-                    }
-
-                    param += lhs_value * rhs_value
+        let mut params = vec![T::zero(); self.rows * rhs.cols];
+
+        // The product is accumulated in row-by-row (i-k-j) order so that
+        // both the lhs row and the rhs rows are walked contiguously
+        // through memory, instead of striding down the rhs columns.
+        for row_idx in 0..self.rows {
+            let out_row = &mut params[row_idx * rhs.cols..(row_idx + 1) * rhs.cols];
+
+            for index in 0..self.cols {
+                let lhs_value = self.matrix[row_idx * self.cols + index];
+                let rhs_row = &rhs.matrix[index * rhs.cols..(index + 1) * rhs.cols];
+
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs_row) {
+                    *param += lhs_value * *rhs_value
                 }
-                params[out_row_index].push(param)
             }
         }
-        Matrix::from(params)
+
+        Matrix::from_parts(self.rows, rhs.cols, params)
     }
 }
 
@@ -351,7 +301,7 @@ where
 /// in the right hand side [matrix][crate::matrix_impl::Matrix].
 impl<T> Mul for Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Matrix<T>;
 
@@ -360,42 +310,25 @@ where
             panic!("The left matrix row count is not equal to the right matrix column count.")
         }
 
-        let mut params: Vec<Vec<T>> = vec![Vec::with_capacity(self.cols); self.rows];
-
-        for out_row_index in 0..self.rows {
-            for out_col_index in 0..rhs.cols {
-                let mut param = T::default();
-                
-                // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-                for index in 0..self.cols {
-                    //get the data from the lhs's row
-                    let lhs_row_ptr = self.matrix[out_row_index].as_ptr();
-                    let lhs_value: T;
-
-                    // SAFTEY: self.cols is guarenteed to be one-greater-than
-                    //         the last index for the lhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        lhs_value = *lhs_row_ptr.add(index)
-                    }
-
-                    //get the data from the rhs's col
-                    let rhs_row_ptr = rhs.matrix[index].as_ptr();
-                    let rhs_value: T;
-
-                    // SAFETY: rhs.rows is guarenteed to be one-greater-than
-                    //         the last index in the rhs_row_ptr and therefore
-                    //         will never go out of range.
-                    unsafe {
-                        rhs_value = *rhs_row_ptr.add(out_col_index)
-                    }
-
-                    param += lhs_value * rhs_value
+        let mut params = vec![T::zero(); self.rows * rhs.cols];
+
+        // The product is accumulated in row-by-row (i-k-j) order so that
+        // both the lhs row and the rhs rows are walked contiguously
+        // through memory, instead of striding down the rhs columns.
+        for row_idx in 0..self.rows {
+            let out_row = &mut params[row_idx * rhs.cols..(row_idx + 1) * rhs.cols];
+
+            for index in 0..self.cols {
+                let lhs_value = self.matrix[row_idx * self.cols + index];
+                let rhs_row = &rhs.matrix[index * rhs.cols..(index + 1) * rhs.cols];
+
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs_row) {
+                    *param += lhs_value * *rhs_value
                 }
-                params[out_row_index].push(param)
             }
         }
-        Matrix::from(params)
+
+        Matrix::from_parts(self.rows, rhs.cols, params)
     }
 }
 
@@ -437,7 +370,7 @@ where
 /// in [Vector][crate::vector_impl::Vector].
 impl<T> Mul<&Vector<T>> for &Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Vector<T>;
 
@@ -446,20 +379,13 @@ where
             panic!("The matrix column count must be equal to the vector parameter count.")
         };
         let mut params = Vec::with_capacity(self.rows);
-        let vector_ptr = rhs.list().as_ptr();
-
-        // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-        for row in &self.matrix {
-            let mut param = T::default();
-
-            let row_ptr = row.as_ptr();
-            
-            for i in 0..self.cols {
-                // SAFETY: rhs.len() == self.cols and therefore
-                //         it will always be in range
-                unsafe {
-                    param += *row_ptr.add(i) * *vector_ptr.add(i)
-                }
+
+        for row_idx in 0..self.rows {
+            let row = &self.matrix[row_idx * self.cols..(row_idx + 1) * self.cols];
+            let mut param = T::zero();
+
+            for (lhs_value, rhs_value) in row.iter().zip(rhs.list()) {
+                param += *lhs_value * *rhs_value
             }
 
             params.push(param)
@@ -499,7 +425,7 @@ where
 /// in [Vector][crate::vector_impl::Vector].
 impl<T> Mul<Vector<T>> for &Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Vector<T>;
 
@@ -508,20 +434,13 @@ where
             panic!("The matrix column count must be equal to the vector parameter count.")
         };
         let mut params = Vec::with_capacity(self.rows);
-        let vector_ptr = rhs.list().as_ptr();
-
-        // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-        for row in &self.matrix {
-            let mut param = T::default();
-
-            let row_ptr = row.as_ptr();
-            
-            for i in 0..self.cols {
-                // SAFETY: rhs.len() == self.cols and therefore
-                //         it will always be in range
-                unsafe {
-                    param += *row_ptr.add(i) * *vector_ptr.add(i)
-                }
+
+        for row_idx in 0..self.rows {
+            let row = &self.matrix[row_idx * self.cols..(row_idx + 1) * self.cols];
+            let mut param = T::zero();
+
+            for (lhs_value, rhs_value) in row.iter().zip(rhs.list()) {
+                param += *lhs_value * *rhs_value
             }
 
             params.push(param)
@@ -561,7 +480,7 @@ where
 /// in [Vector][crate::vector_impl::Vector].
 impl<T> Mul<&Vector<T>> for Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Vector<T>;
 
@@ -570,20 +489,13 @@ where
             panic!("The matrix column count must be equal to the vector parameter count.")
         };
         let mut params = Vec::with_capacity(self.rows);
-        let vector_ptr = rhs.list().as_ptr();
-
-        // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-        for row in &self.matrix {
-            let mut param = T::default();
-
-            let row_ptr = row.as_ptr();
-            
-            for i in 0..self.cols {
-                // SAFETY: rhs.len() == self.cols and therefore
-                //         it will always be in range
-                unsafe {
-                    param += *row_ptr.add(i) * *vector_ptr.add(i)
-                }
+
+        for row_idx in 0..self.rows {
+            let row = &self.matrix[row_idx * self.cols..(row_idx + 1) * self.cols];
+            let mut param = T::zero();
+
+            for (lhs_value, rhs_value) in row.iter().zip(rhs.list()) {
+                param += *lhs_value * *rhs_value
             }
 
             params.push(param)
@@ -623,7 +535,7 @@ where
 /// in [Vector][crate::vector_impl::Vector].
 impl<T> Mul<Vector<T>> for Matrix<T>
 where
-    T: Copy + AddAssign + Mul<Output = T> + Default
+    T: Ring
 {
     type Output = Vector<T>;
 
@@ -632,20 +544,13 @@ where
             panic!("The matrix column count must be equal to the vector parameter count.")
         };
         let mut params = Vec::with_capacity(self.rows);
-        let vector_ptr = rhs.list().as_ptr();
-
-        // SAFTEY: The previous test guarentees that valid memory will be pointed to.
-        for row in &self.matrix {
-            let mut param = T::default();
-
-            let row_ptr = row.as_ptr();
-            
-            for i in 0..self.cols {
-                // SAFETY: rhs.len() == self.cols and therefore
-                //         it will always be in range
-                unsafe {
-                    param += *row_ptr.add(i) * *vector_ptr.add(i)
-                }
+
+        for row_idx in 0..self.rows {
+            let row = &self.matrix[row_idx * self.cols..(row_idx + 1) * self.cols];
+            let mut param = T::zero();
+
+            for (lhs_value, rhs_value) in row.iter().zip(rhs.list()) {
+                param += *lhs_value * *rhs_value
             }
 
             params.push(param)
@@ -690,19 +595,11 @@ where
     type Output = Matrix<T>;
 
     fn mul(self, rhs: T) -> Self::Output {
-        let mut params = Vec::with_capacity(self.rows);
-
-        for row_idx in 0..self.rows {
-            let mut params_row = Vec::with_capacity(self.cols);
-            
-            for col_idx in 0..self.cols {
-                params_row.push(rhs * self.matrix[row_idx][col_idx])
-            }
-            
-            params.push(params_row)
-        }
+        let params = self.matrix.iter()
+            .map(|val| rhs * *val)
+            .collect();
 
-        Matrix::from(params)
+        Matrix::from_parts(self.rows, self.cols, params)
     }
 }
 
@@ -733,19 +630,9 @@ where
 {
     type Output = Matrix<T>;
 
-    fn mul(self, rhs: T) -> Self::Output {
-        let mut params = Vec::with_capacity(self.rows);
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
 
-        for row_idx in 0..self.rows {
-            let mut params_row = Vec::with_capacity(self.cols);
-            
-            for col_idx in 0..self.cols {
-                params_row.push(rhs * self.matrix[row_idx][col_idx])
-            }
-            
-            params.push(params_row)
-        }
-        
-        Matrix::from(params)
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,86 @@
+use std::ops::Neg;
+use crate::matrix_impl::Matrix;
+
+//
+//
+//          Borrowed Implementation
+//
+//
+/// [Negation][std::ops::Neg] implementation of '-&Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[1, -2],
+///                       [3, -4]];
+/// 
+/// let matrix2 = -&matrix1;
+/// 
+/// assert_eq!(matrix2, matrix![[-1, 2],
+///                             [-3, 4]]);
+/// ```
+impl<T> Neg for &Matrix<T>
+where
+    T: Neg<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        let params = self.matrix.iter()
+            .map(|val| -*val)
+            .collect();
+
+        Matrix::from_parts(self.rows, self.cols, params)
+    }
+}
+
+
+//
+//
+//          Owned Implementation
+//
+//
+/// [Negation][std::ops::Neg] implementation of '-Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[1, -2],
+///                       [3, -4]];
+/// 
+/// let matrix2 = -matrix1;
+/// 
+/// assert_eq!(matrix2, matrix![[-1, 2],
+///                             [-3, 4]]);
+/// ```
+/// This is useful for negating the result of another operation.
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[1, 2],
+///                       [3, 4]];
+/// 
+/// let matrix2 = -(&matrix1 * 2);
+/// 
+/// assert_eq!(matrix2, matrix![[-2, -4],
+///                             [-6, -8]]);
+/// ```
+impl<T> Neg for Matrix<T>
+where
+    T: Neg<Output = T>
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        let params = self.matrix.into_iter()
+            .map(|val| -val)
+            .collect();
+
+        Matrix::from_parts(self.rows, self.cols, params)
+    }
+}
@@ -0,0 +1,166 @@
+use crate::matrix_impl::Matrix;
+use crate::error::{Shape, ShapeError};
+
+impl<T> Matrix<T>
+where
+    T: Clone
+{
+    /// Joins matrices side by side, from left to right.
+    /// 
+    /// Stacking no matrices at all gives a `0x0` Matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4]];
+    /// let rhs = matrix![[5],
+    ///                   [6]];
+    /// 
+    /// // The augmented matrix [A | b].
+    /// assert_eq!(Matrix::hstack(&[&matrix, &rhs]), Ok(matrix![[1, 2, 5],
+    ///                                                         [3, 4, 6]]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns a [ShapeError] holding the first Matrix and the first Matrix
+    /// whose number of rows differs from it.
+    pub fn hstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, ShapeError> {
+        hstack(matrices, "hstack")
+    }
+
+    /// Joins matrices on top of each other, from top to bottom.
+    /// 
+    /// Stacking no matrices at all gives a `0x0` Matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::error::Shape;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4]];
+    /// 
+    /// assert_eq!(Matrix::vstack(&[&matrix, &matrix![[5, 6]]]), Ok(matrix![[1, 2],
+    ///                                                                     [3, 4],
+    ///                                                                     [5, 6]]));
+    /// 
+    /// let error = Matrix::vstack(&[&matrix, &matrix![[5, 6, 7]]]).unwrap_err();
+    /// 
+    /// assert_eq!(error.lhs(), Shape::Matrix(2, 2));
+    /// assert_eq!(error.rhs(), Shape::Matrix(1, 3));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns a [ShapeError] holding the first Matrix and the first Matrix
+    /// whose number of columns differs from it.
+    pub fn vstack(matrices: &[&Matrix<T>]) -> Result<Matrix<T>, ShapeError> {
+        vstack(matrices.iter().copied(), "vstack")
+    }
+
+    /// Assembles a Matrix from a grid of blocks, given as a list of block rows.
+    /// 
+    /// The blocks within a block row must have the same number of rows,
+    /// and every block row must have the same total number of columns.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let hessian = matrix![[2, 0],
+    ///                       [0, 2]];
+    /// let constraints = matrix![[1, 1]];
+    /// let zero = matrix![[0]];
+    /// 
+    /// // The KKT matrix [[H, Aᵀ], [A, 0]].
+    /// let kkt = Matrix::block(vec![vec![&hessian, &constraints.transpose()],
+    ///                              vec![&constraints, &zero]]);
+    /// 
+    /// assert_eq!(kkt, Ok(matrix![[2, 0, 1],
+    ///                            [0, 2, 1],
+    ///                            [1, 1, 0]]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns a [ShapeError] holding the first mismatched pair of blocks within a
+    /// block row, or the first mismatched pair of assembled block rows.
+    pub fn block(blocks: Vec<Vec<&Matrix<T>>>) -> Result<Matrix<T>, ShapeError> {
+        let mut block_rows = Vec::with_capacity(blocks.len());
+
+        for block_row in blocks {
+            block_rows.push(hstack(&block_row, "block")?)
+        }
+
+        vstack(block_rows.iter(), "block")
+    }
+}
+
+fn hstack<T>(matrices: &[&Matrix<T>], operation: &'static str) -> Result<Matrix<T>, ShapeError>
+where
+    T: Clone
+{
+    let first = match matrices.first() {
+        Some(first) => first,
+        None => return Ok(Matrix::from_parts(0, 0, Vec::new()))
+    };
+
+    let rows = first.rows();
+    let mut cols = 0;
+
+    for matrix in matrices {
+        if matrix.rows() != rows {
+            return Err(ShapeError::new(
+                operation,
+                Shape::Matrix(first.rows(), first.cols()),
+                Shape::Matrix(matrix.rows(), matrix.cols())
+            ))
+        }
+
+        cols += matrix.cols()
+    }
+
+    let mut params = Vec::with_capacity(rows * cols);
+
+    for row_idx in 0..rows {
+        for matrix in matrices {
+            params.extend_from_slice(matrix.row(row_idx))
+        }
+    }
+
+    Ok(Matrix::from_parts(rows, cols, params))
+}
+
+fn vstack<'a, T, I>(matrices: I, operation: &'static str) -> Result<Matrix<T>, ShapeError>
+where
+    T: Clone + 'a,
+    I: Iterator<Item = &'a Matrix<T>> + Clone
+{
+    let first = match matrices.clone().next() {
+        Some(first) => first,
+        None => return Ok(Matrix::from_parts(0, 0, Vec::new()))
+    };
+
+    let cols = first.cols();
+    let mut rows = 0;
+
+    for matrix in matrices.clone() {
+        if matrix.cols() != cols {
+            return Err(ShapeError::new(
+                operation,
+                Shape::Matrix(first.rows(), first.cols()),
+                Shape::Matrix(matrix.rows(), matrix.cols())
+            ))
+        }
+
+        rows += matrix.rows()
+    }
+
+    let mut params = Vec::with_capacity(rows * cols);
+
+    for matrix in matrices {
+        params.extend_from_slice(matrix.as_slice())
+    }
+
+    Ok(Matrix::from_parts(rows, cols, params))
+}
@@ -0,0 +1,254 @@
+use std::ops::Sub;
+use crate::matrix_impl::Matrix;
+
+//
+//
+//          Borrowed/Borrowed Implementation
+//
+//
+/// [Subtraction][std::ops::Sub] implementation of '&Matrix<T> - &Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// let matrix3 = &matrix1 - &matrix2;
+/// 
+/// assert_eq!(matrix3, matrix![[6, 6],
+///                             [6, 6],
+///                             [6, 6]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> Sub for &Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be subtracted from each other.")
+        }
+
+        let params = self.matrix.iter()
+            .zip(rhs.matrix.iter())
+            .map(|(lhs_val, rhs_val)| *lhs_val - *rhs_val)
+            .collect();
+
+        Matrix::from_parts(self.rows, self.cols, params)
+    }
+}
+
+
+//
+//
+//          Borrowed/Owned Implementation
+//
+//
+/// [Subtraction][std::ops::Sub] implementation of '&Matrix<T> - Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// let matrix3 = &matrix1 - matrix2;
+/// 
+/// assert_eq!(matrix3, matrix![[6, 6],
+///                             [6, 6],
+///                             [6, 6]]);
+/// ```
+/// This is useful for subtraction of matrices that are scaled.
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// // The result of '&matrix2 * 2' is an owned Matrix,
+/// // which is then subtracted from '&matrix1'.
+/// let matrix3 = &matrix1 - &matrix2 * 2;
+/// 
+/// assert_eq!(matrix3, matrix![[5, 4],
+///                             [3, 2],
+///                             [1, 0]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> Sub<Matrix<T>> for &Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, mut rhs: Matrix<T>) -> Self::Output {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be subtracted from each other.")
+        }
+
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (lhs_val, rhs_val) in self.matrix.iter().zip(rhs.matrix.iter_mut()) {
+            *rhs_val = *lhs_val - *rhs_val
+        }
+
+        rhs
+    }
+}
+
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// [Subtraction][std::ops::Sub] implementation of 'Matrix<T> - &Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// let matrix3 = matrix1 - &matrix2;
+/// 
+/// assert_eq!(matrix3, matrix![[6, 6],
+///                             [6, 6],
+///                             [6, 6]]);
+/// ```
+/// This is useful for subtraction of matrices that are scaled.
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// // The result of '&matrix1 * 2' is an owned Matrix,
+/// // which '&matrix2' is then subtracted from.
+/// let matrix3 = &matrix1 * 2 - &matrix2;
+/// 
+/// assert_eq!(matrix3, matrix![[13, 14],
+///                             [15, 16],
+///                             [17, 18]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> Sub<&Matrix<T>> for Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: &Matrix<T>) -> Self::Output {
+        self -= rhs;
+
+        self
+    }
+}
+
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// [Subtraction][std::ops::Sub] implementation of 'Matrix<T> - Matrix<T>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// let matrix3 = matrix1 - matrix2;
+/// 
+/// assert_eq!(matrix3, matrix![[6, 6],
+///                             [6, 6],
+///                             [6, 6]]);
+/// ```
+/// This is useful for subtraction of matrices that are scaled.
+/// ```
+/// use simp_linalg::matrix_impl::Matrix;
+/// use simp_linalg::matrix;
+/// 
+/// let matrix1 = matrix![[7,  8],
+///                       [9,  10],
+///                       [11, 12]];
+/// 
+/// let matrix2 = matrix![[1, 2],
+///                       [3, 4],
+///                       [5, 6]];
+/// 
+/// // The result of '&matrix1 * 2' is an owned Matrix,
+/// // which another owned matrix '&matrix2 * 3' is subtracted from.
+/// let matrix3 = &matrix1 * 2 - &matrix2 * 3;
+/// 
+/// assert_eq!(matrix3, matrix![[11, 10],
+///                             [9,  8],
+///                             [7,  6]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not
+/// equivalent in size.
+impl<T> Sub for Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+
+        self
+    }
+}
@@ -0,0 +1,125 @@
+use crate::matrix_impl::Matrix;
+
+impl<T> Matrix<T> {
+    /// Returns the transpose of the matrix.
+    /// 
+    /// Each element is cloned exactly once, straight into its place in the
+    /// result, by reading the columns of the matrix in order.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// assert_eq!(matrix.transpose(), matrix![[1, 4],
+    ///                                        [2, 5],
+    ///                                        [3, 6]]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T>
+    where
+        T: Clone
+    {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut params = Vec::with_capacity(rows * cols);
+
+        params.extend((0..cols).flat_map(|col_idx| {
+            (0..rows).map(move |row_idx| self.matrix[row_idx * cols + col_idx].clone())
+        }));
+
+        Matrix::from_parts(self.cols, self.rows, params)
+    }
+
+    /// Transposes the matrix, consuming it.
+    /// 
+    /// The elements are moved rather than copied, so the internal type does
+    /// not need to implement [Clone] or [Copy].
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let matrix = matrix![[String::from("a"), String::from("b")],
+    ///                      [String::from("c"), String::from("d")],
+    ///                      [String::from("e"), String::from("f")]];
+    /// 
+    /// assert_eq!(matrix.into_transpose(), matrix![[String::from("a"), String::from("c"), String::from("e")],
+    ///                                             [String::from("b"), String::from("d"), String::from("f")]]);
+    /// ```
+    pub fn into_transpose(mut self) -> Matrix<T> {
+        self.transpose_mut();
+
+        self
+    }
+
+    /// Transposes the matrix in place.
+    /// 
+    /// Square matrices are transposed by swapping across the diagonal.
+    /// Rectangular matrices are transposed within the same buffer by following
+    /// the permutation cycles, which only requires one byte of scratch space
+    /// per element to mark the visited positions.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::matrix_impl::Matrix;
+    /// use simp_linalg::matrix;
+    /// 
+    /// let mut matrix = matrix![[1, 2],
+    ///                          [3, 4]];
+    /// 
+    /// matrix.transpose_mut();
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 3],
+    ///                            [2, 4]]);
+    /// ```
+    pub fn transpose_mut(&mut self) {
+        if self.rows == self.cols {
+            let size = self.rows;
+
+            for row_idx in 0..size {
+                for col_idx in row_idx + 1..size {
+                    self.matrix.swap(row_idx * size + col_idx, col_idx * size + row_idx)
+                }
+            }
+
+            return
+        }
+
+        let rows = self.rows;
+        let cols = self.cols;
+        let len = self.matrix.len();
+
+        // The element at 'idx' belongs at 'destination(idx)' once transposed.
+        let destination = |idx: usize| (idx % cols) * rows + idx / cols;
+
+        let mut visited = vec![false; len];
+
+        for start in 0..len {
+            if visited[start] {
+                continue
+            }
+
+            // The slot at 'start' holds the element that still needs a home,
+            // and each swap settles one element of the cycle into its place.
+            let mut current = start;
+
+            loop {
+                let next = destination(current);
+                visited[next] = true;
+
+                if next == start {
+                    break
+                }
+
+                self.matrix.swap(start, next);
+                current = next
+            }
+        }
+
+        self.rows = cols;
+        self.cols = rows;
+    }
+}
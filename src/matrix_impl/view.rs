@@ -0,0 +1,777 @@
+use std::ops::{Add, Sub, Mul, Index, IndexMut, Bound, Range, RangeBounds};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::traits::Ring;
+
+/// A borrowed, rectangular block of a [Matrix], created by
+/// [Matrix::view], [Matrix::row_view] or [Matrix::col_view].
+/// 
+/// No elements are copied. The block is read through the parent's row-major
+/// buffer, where consecutive rows of the view are `stride` elements apart.
+/// 
+/// Views can be used as operands of `+`, `-` and `*` together with other
+/// views and [Matrices][Matrix], producing an owned [Matrix].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[1, 2, 3],
+///                      [4, 5, 6],
+///                      [7, 8, 9]];
+/// 
+/// let top_left = matrix.view(..2, ..2);
+/// let bottom_right = matrix.view(1.., 1..);
+/// 
+/// assert_eq!(top_left + bottom_right, matrix![[ 6,  8],
+///                                             [12, 14]]);
+/// assert_eq!(top_left * bottom_right, matrix![[21, 24],
+///                                             [60, 69]]);
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    data : &'a [T],
+    rows : usize,
+    cols : usize,
+    stride : usize
+}
+
+/// A mutably borrowed, rectangular block of a [Matrix], created by
+/// [Matrix::view_mut], [Matrix::row_view_mut] or [Matrix::col_view_mut].
+/// 
+/// Writes through the view go directly into the parent Matrix.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let mut matrix = matrix![[1, 2, 3],
+///                          [4, 5, 6]];
+/// 
+/// let mut col = matrix.col_view_mut(1);
+/// for val in col.iter_mut() {
+///     *val *= 10
+/// }
+/// 
+/// assert_eq!(matrix, matrix![[1, 20, 3],
+///                            [4, 50, 6]]);
+/// ```
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    data : &'a mut [T],
+    rows : usize,
+    cols : usize,
+    stride : usize
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+// Resolves a range over 'len' rows or columns, panicking if it is out of range.
+fn resolve<R>(range: R, len: usize, message: &str) -> Range<usize>
+where
+    R: RangeBounds<usize>
+{
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len
+    };
+
+    if start > end || end > len {
+        panic!("{}", message)
+    }
+
+    start..end
+}
+
+// The range of a row-major buffer spanned by a block, from its
+// first element to the last element of its last row.
+fn span(rows: Range<usize>, cols: Range<usize>, stride: usize) -> Range<usize> {
+    let start = rows.start * stride + cols.start;
+
+    if rows.is_empty() || cols.is_empty() {
+        return start..start
+    }
+
+    start..(rows.end - 1) * stride + cols.end
+}
+
+impl<T> Matrix<T> {
+    /// Borrows the block of the Matrix covering the given rows and columns.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6],
+    ///                      [7, 8, 9]];
+    /// 
+    /// let view = matrix.view(1..3, 0..=1);
+    /// 
+    /// assert_eq!((view.rows(), view.cols()), (2, 2));
+    /// assert_eq!(view[(1, 0)], 7);
+    /// assert_eq!(view.to_matrix(), matrix![[4, 5],
+    ///                                      [7, 8]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if either range is out of range for the Matrix.
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>
+    {
+        let rows = resolve(rows, self.rows, "Row range out of range for the matrix.");
+        let cols = resolve(cols, self.cols, "Column range out of range for the matrix.");
+
+        MatrixView {
+            data : &self.matrix[span(rows.clone(), cols.clone(), self.cols)],
+            rows : rows.len(),
+            cols : cols.len(),
+            stride : self.cols
+        }
+    }
+
+    /// Mutably borrows the block of the Matrix covering the given rows and columns.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let mut matrix = matrix![[1, 2, 3],
+    ///                          [4, 5, 6]];
+    /// 
+    /// matrix.view_mut(.., 1..).copy_from(matrix![[0, 0],
+    ///                                            [0, 0]].view(.., ..));
+    /// 
+    /// assert_eq!(matrix, matrix![[1, 0, 0],
+    ///                            [4, 0, 0]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if either range is out of range for the Matrix.
+    pub fn view_mut<R, C>(&mut self, rows: R, cols: C) -> MatrixViewMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>
+    {
+        let rows = resolve(rows, self.rows, "Row range out of range for the matrix.");
+        let cols = resolve(cols, self.cols, "Column range out of range for the matrix.");
+
+        MatrixViewMut {
+            data : &mut self.matrix[span(rows.clone(), cols.clone(), self.cols)],
+            rows : rows.len(),
+            cols : cols.len(),
+            stride : self.cols
+        }
+    }
+
+    /// Borrows a single row as a `1 x cols` view.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range for the Matrix.
+    pub fn row_view(&self, row: usize) -> MatrixView<'_, T> {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        self.view(row..=row, ..)
+    }
+
+    /// Borrows a single column as a `rows x 1` view, whose
+    /// elements are `cols` apart in the underlying buffer.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2],
+    ///                      [3, 4],
+    ///                      [5, 6]];
+    /// 
+    /// let col = matrix.col_view(1);
+    /// 
+    /// assert_eq!(col.iter().copied().collect::<Vec<_>>(), vec![2, 4, 6]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the column is out of range for the Matrix.
+    pub fn col_view(&self, col: usize) -> MatrixView<'_, T> {
+        if col >= self.cols {
+            panic!("Column index out of range for the matrix.")
+        }
+
+        self.view(.., col..=col)
+    }
+
+    /// Mutably borrows a single row as a `1 x cols` view.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range for the Matrix.
+    pub fn row_view_mut(&mut self, row: usize) -> MatrixViewMut<'_, T> {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        self.view_mut(row..=row, ..)
+    }
+
+    /// Mutably borrows a single column as a `rows x 1` view.
+    /// 
+    /// # Panic!
+    /// This function will panic if the column is out of range for the Matrix.
+    pub fn col_view_mut(&mut self, col: usize) -> MatrixViewMut<'_, T> {
+        if col >= self.cols {
+            panic!("Column index out of range for the matrix.")
+        }
+
+        self.view_mut(.., col..=col)
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T> {
+    fn from(matrix: &'a Matrix<T>) -> Self {
+        matrix.view(.., ..)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Returns the number of rows in the view.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the view.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the distance in the underlying buffer between
+    /// the starts of consecutive rows of the view.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Borrows the element at the given row and column, or returns [None]
+    /// if either index is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        Some(&self.data[row * self.stride + col])
+    }
+
+    /// Borrows a row of the view, which is always contiguous.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range for the view.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        // A view without columns borrows no elements, so its rows are all empty.
+        if self.cols == 0 {
+            return &[]
+        }
+
+        &self.data[row * self.stride..][..self.cols]
+    }
+
+    /// Returns an iterator over the rows of the view as slices.
+    pub fn row_iter(&self) -> impl Iterator<Item = &'a [T]> {
+        let (data, stride, cols) = (self.data, self.stride, self.cols);
+
+        (0..self.rows).map(move |row| {
+            if cols == 0 {
+                return &[][..]
+            }
+
+            &data[row * stride..][..cols]
+        })
+    }
+
+    /// Returns an iterator over the elements of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.row_iter().flatten()
+    }
+
+    /// Borrows a block of this view, with the ranges relative to the view.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6],
+    ///                      [7, 8, 9]];
+    /// 
+    /// let view = matrix.view(1.., 1..).view(1.., ..1);
+    /// 
+    /// assert_eq!(view.to_matrix(), matrix![[8]]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if either range is out of range for the view.
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'a, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>
+    {
+        let rows = resolve(rows, self.rows, "Row range out of range for the matrix.");
+        let cols = resolve(cols, self.cols, "Column range out of range for the matrix.");
+
+        MatrixView {
+            data : &self.data[span(rows.clone(), cols.clone(), self.stride)],
+            rows : rows.len(),
+            cols : cols.len(),
+            stride : self.stride
+        }
+    }
+
+    /// Copies the elements of the view into a new Matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone
+    {
+        Matrix::from_parts(self.rows, self.cols, self.iter().cloned().collect())
+    }
+
+    /// Applies `funct` to every element of the view, collecting the results into a new Matrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = matrix![[1, 2, 3],
+    ///                      [4, 5, 6]];
+    /// 
+    /// assert_eq!(matrix.col_view(2).lambda(|val| val * val), matrix![[9],
+    ///                                                                [36]]);
+    /// ```
+    pub fn lambda<F>(&self, funct: F) -> Matrix<T>
+    where
+        F: Fn(&T) -> T
+    {
+        Matrix::from_parts(self.rows, self.cols, self.iter().map(funct).collect())
+    }
+
+    /// Applies `funct` to every element of the view along with its row and column
+    /// within the view, collecting the results into a new Matrix.
+    pub fn lambda_enumerate<F>(&self, funct: F) -> Matrix<T>
+    where
+        F: Fn(usize, usize, &T) -> T
+    {
+        let params = self.row_iter()
+            .enumerate()
+            .flat_map(|(row_idx, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(col_idx, val)| (row_idx, col_idx, val))
+            })
+            .map(|(row_idx, col_idx, val)| funct(row_idx, col_idx, val))
+            .collect();
+
+        Matrix::from_parts(self.rows, self.cols, params)
+    }
+}
+
+impl<T> MatrixViewMut<'_, T> {
+    /// Returns the number of rows in the view.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the view.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the distance in the underlying buffer between
+    /// the starts of consecutive rows of the view.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Reborrows the view immutably.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data : &*self.data,
+            rows : self.rows,
+            cols : self.cols,
+            stride : self.stride
+        }
+    }
+
+    /// Borrows the element at the given row and column, or returns [None]
+    /// if either index is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        Some(&self.data[row * self.stride + col])
+    }
+
+    /// Mutably borrows the element at the given row and column, or returns [None]
+    /// if either index is out of range.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        Some(&mut self.data[row * self.stride + col])
+    }
+
+    /// Mutably borrows a row of the view, which is always contiguous.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range for the view.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        // A view without columns borrows no elements, so its rows are all empty.
+        if self.cols == 0 {
+            return &mut []
+        }
+
+        &mut self.data[row * self.stride..][..self.cols]
+    }
+
+    /// Returns an iterator over mutable references to the elements of the view
+    /// in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let cols = self.cols;
+
+        self.data
+            .chunks_mut(self.stride.max(1))
+            .take(self.rows)
+            .flat_map(move |row| &mut row[..cols])
+    }
+
+    /// Mutably borrows a block of this view, with the ranges relative to the view.
+    /// 
+    /// # Panic!
+    /// This function will panic if either range is out of range for the view.
+    pub fn view_mut<R, C>(&mut self, rows: R, cols: C) -> MatrixViewMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>
+    {
+        let rows = resolve(rows, self.rows, "Row range out of range for the matrix.");
+        let cols = resolve(cols, self.cols, "Column range out of range for the matrix.");
+
+        MatrixViewMut {
+            data : &mut self.data[span(rows.clone(), cols.clone(), self.stride)],
+            rows : rows.len(),
+            cols : cols.len(),
+            stride : self.stride
+        }
+    }
+
+    /// Overwrites the elements of the view with those of `source`.
+    /// 
+    /// # Panic!
+    /// This function will panic if `source` does not have the same shape as the view.
+    pub fn copy_from(&mut self, source: MatrixView<'_, T>)
+    where
+        T: Clone
+    {
+        if self.rows != source.rows || self.cols != source.cols {
+            panic!("Cannot copy between views of different sizes.")
+        }
+
+        for (row_idx, row) in source.row_iter().enumerate() {
+            self.row_mut(row_idx).clone_from_slice(row)
+        }
+    }
+
+    /// Copies the elements of the view into a new Matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone
+    {
+        self.as_view().to_matrix()
+    }
+
+    /// Applies `funct` to every element of the view, collecting the results into a new Matrix.
+    pub fn lambda<F>(&self, funct: F) -> Matrix<T>
+    where
+        F: Fn(&T) -> T
+    {
+        self.as_view().lambda(funct)
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col).expect("Index out of range for the matrix.")
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col).expect("Index out of range for the matrix.")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col).expect("Index out of range for the matrix.")
+    }
+}
+
+// Combines two equally shaped views element by element.
+fn zip_views<T, F>(lhs: MatrixView<'_, T>, rhs: MatrixView<'_, T>, funct: F, message: &str) -> Matrix<T>
+where
+    T: Copy,
+    F: Fn(T, T) -> T
+{
+    if lhs.rows != rhs.rows || lhs.cols != rhs.cols {
+        panic!("{}", message)
+    }
+
+    let params = lhs.iter()
+        .zip(rhs.iter())
+        .map(|(lhs, rhs)| funct(*lhs, *rhs))
+        .collect();
+
+    Matrix::from_parts(lhs.rows, lhs.cols, params)
+}
+
+// Multiplies two views, in the same i-k-j order as '&Matrix * &Matrix'.
+fn mul_views<T>(lhs: MatrixView<'_, T>, rhs: MatrixView<'_, T>) -> Matrix<T>
+where
+    T: Ring
+{
+    if lhs.cols != rhs.rows {
+        panic!("The left matrix row count is not equal to the right matrix column count.")
+    }
+
+    let mut params = vec![T::zero(); lhs.rows * rhs.cols];
+
+    for (lhs_row, product_row) in lhs.row_iter().zip(params.chunks_mut(rhs.cols.max(1))) {
+        for (lhs_val, rhs_row) in lhs_row.iter().zip(rhs.row_iter()) {
+            for (product, rhs_val) in product_row.iter_mut().zip(rhs_row) {
+                *product += *lhs_val * *rhs_val
+            }
+        }
+    }
+
+    Matrix::from_parts(lhs.rows, rhs.cols, params)
+}
+
+//
+//
+//          View + View Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for 'MatrixView + MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the views are not the same size.
+impl<T> Add for MatrixView<'_, T>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        zip_views(self, rhs, |lhs, rhs| lhs + rhs, "Differently sized matrices cannot be added together.")
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for 'MatrixView + &Matrix'.
+/// 
+/// # Panic!
+/// This function will panic if the operands are not the same size.
+impl<T> Add<&Matrix<T>> for MatrixView<'_, T>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        self + rhs.view(.., ..)
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&Matrix + MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the operands are not the same size.
+impl<T> Add<MatrixView<'_, T>> for &Matrix<T>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: MatrixView<'_, T>) -> Self::Output {
+        self.view(.., ..) + rhs
+    }
+}
+
+//
+//
+//          View - View Implementations
+//
+//
+/// The [subtraction][std::ops::Sub] implementation for 'MatrixView - MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the views are not the same size.
+impl<T> Sub for MatrixView<'_, T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        zip_views(self, rhs, |lhs, rhs| lhs - rhs, "Differently sized matrices cannot be subtracted from each other.")
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for 'MatrixView - &Matrix'.
+/// 
+/// # Panic!
+/// This function will panic if the operands are not the same size.
+impl<T> Sub<&Matrix<T>> for MatrixView<'_, T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        self - rhs.view(.., ..)
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for '&Matrix - MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the operands are not the same size.
+impl<T> Sub<MatrixView<'_, T>> for &Matrix<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: MatrixView<'_, T>) -> Self::Output {
+        self.view(.., ..) - rhs
+    }
+}
+
+//
+//
+//          View * View Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for 'MatrixView * MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// is not equal to the number of rows in the right hand side.
+impl<T> Mul for MatrixView<'_, T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        mul_views(self, rhs)
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'MatrixView * &Matrix'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// is not equal to the number of rows in the right hand side.
+impl<T> Mul<&Matrix<T>> for MatrixView<'_, T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        mul_views(self, rhs.view(.., ..))
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Matrix * MatrixView'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// is not equal to the number of rows in the right hand side.
+impl<T> Mul<MatrixView<'_, T>> for &Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: MatrixView<'_, T>) -> Self::Output {
+        mul_views(self.view(.., ..), rhs)
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'MatrixView * &Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = matrix![[1, 2, 3],
+///                      [4, 5, 6]];
+/// 
+/// assert_eq!(matrix.view(.., 1..) * &vector![1, -1], vector![-1, -1]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the view
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for MatrixView<'_, T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if rhs.len() != self.cols {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        let mut params = Vec::with_capacity(self.rows);
+
+        for row in self.row_iter() {
+            let mut param = T::zero();
+
+            for (lhs_value, rhs_value) in row.iter().zip(rhs.list()) {
+                param += *lhs_value * *rhs_value
+            }
+
+            params.push(param)
+        }
+
+        Vector::from(params)
+    }
+}
@@ -1,3 +1,4 @@
 pub use crate::matrix_impl::Matrix;
 pub use crate::vector_impl::Vector;
+pub use crate::static_impl::{SVector, SMatrix};
 pub use crate::{vector, matrix};
\ No newline at end of file
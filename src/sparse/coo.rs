@@ -0,0 +1,168 @@
+use crate::matrix_impl::Matrix;
+use crate::sparse::{Compressed, CsrMatrix, CscMatrix};
+use crate::traits::{Zero, Ring};
+
+/// A sparse matrix in coordinate (COO) format, storing a list of
+/// `(row, col, value)` triplets.
+/// 
+/// COO is the format for assembling a sparse matrix entry by entry. Once
+/// built, convert it with [to_csr][CooMatrix::to_csr] or [to_csc][CooMatrix::to_csc]
+/// for arithmetic. Duplicate entries are allowed and are summed on conversion.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::sparse::CooMatrix;
+/// 
+/// let mut coo = CooMatrix::new(3, 3);
+/// 
+/// for idx in 0..3 {
+///     coo.push(idx, idx, 2);
+/// }
+/// coo.push(0, 2, 1);
+/// coo.push(0, 2, 4);
+/// 
+/// assert_eq!(Matrix::from(&coo.to_csr()), matrix![[2, 0, 5],
+///                                                 [0, 2, 0],
+///                                                 [0, 0, 2]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows : usize,
+    cols : usize,
+    row_indices : Vec<usize>,
+    col_indices : Vec<usize>,
+    values : Vec<T>
+}
+
+impl<T> CooMatrix<T> {
+    /// Constructs an empty `rows x cols` COO matrix.
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        CooMatrix::with_capacity(rows, cols, 0)
+    }
+
+    /// Constructs an empty `rows x cols` COO matrix with room
+    /// for `capacity` entries.
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> CooMatrix<T> {
+        CooMatrix {
+            rows,
+            cols,
+            row_indices : Vec::with_capacity(capacity),
+            col_indices : Vec::with_capacity(capacity),
+            values : Vec::with_capacity(capacity)
+        }
+    }
+
+    /// Appends an entry. An entry already stored at the same position is
+    /// not replaced; the two are summed on conversion.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row or column is out of range.
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.rows || col >= self.cols {
+            panic!("Index out of range for the matrix.")
+        }
+
+        self.row_indices.push(row);
+        self.col_indices.push(col);
+        self.values.push(value)
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries, counting duplicates.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns an iterator over the stored `(row, col, value)` triplets
+    /// in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.row_indices.iter()
+            .zip(self.col_indices.iter())
+            .zip(self.values.iter())
+            .map(|((row, col), value)| (*row, *col, value))
+    }
+
+    /// Returns the transpose, by swapping the row and column of every entry.
+    pub fn transpose(&self) -> CooMatrix<T>
+    where
+        T: Clone
+    {
+        CooMatrix {
+            rows : self.cols,
+            cols : self.rows,
+            row_indices : self.col_indices.clone(),
+            col_indices : self.row_indices.clone(),
+            values : self.values.clone()
+        }
+    }
+
+    /// Converts into compressed sparse row format, summing duplicate entries.
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Ring
+    {
+        CsrMatrix::from_parts(
+            self.rows,
+            self.cols,
+            Compressed::from_triplets(self.rows, &self.row_indices, &self.col_indices, &self.values)
+        )
+    }
+
+    /// Converts into compressed sparse column format, summing duplicate entries.
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Ring
+    {
+        CscMatrix::from_parts(
+            self.rows,
+            self.cols,
+            Compressed::from_triplets(self.cols, &self.col_indices, &self.row_indices, &self.values)
+        )
+    }
+}
+
+/// Converts a [Matrix] into a [CooMatrix], keeping only the nonzero entries.
+impl<T> From<&Matrix<T>> for CooMatrix<T>
+where
+    T: Zero + PartialEq + Copy
+{
+    fn from(matrix: &Matrix<T>) -> Self {
+        let mut coo = CooMatrix::new(matrix.rows(), matrix.cols());
+
+        for row in 0..matrix.rows() {
+            for (col, value) in matrix.row(row).iter().enumerate() {
+                if *value != T::zero() {
+                    coo.push(row, col, *value)
+                }
+            }
+        }
+
+        coo
+    }
+}
+
+/// Converts a [CooMatrix] into a dense [Matrix], summing duplicate entries.
+impl<T> From<&CooMatrix<T>> for Matrix<T>
+where
+    T: Ring
+{
+    fn from(coo: &CooMatrix<T>) -> Self {
+        let mut matrix = Matrix::zeros(coo.rows, coo.cols);
+
+        for (row, col, value) in coo.iter() {
+            matrix[(row, col)] += *value
+        }
+
+        matrix
+    }
+}
@@ -0,0 +1,262 @@
+use std::ops::{Add, Mul};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::sparse::{Compressed, CsrMatrix};
+use crate::traits::{Zero, Ring};
+
+/// A sparse matrix in compressed sparse column (CSC) format.
+/// 
+/// The entries of each column are stored contiguously and sorted by row,
+/// which makes column access cheap. Build one from a [CooMatrix][crate::sparse::CooMatrix]
+/// or convert it from a dense [Matrix].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::sparse::CscMatrix;
+/// 
+/// let matrix = matrix![[1, 0, 2],
+///                      [0, 0, 3]];
+/// 
+/// let csc = CscMatrix::from(&matrix);
+/// 
+/// assert_eq!(csc.col_offsets(), &[0, 1, 1, 3]);
+/// assert_eq!(csc.row_indices(), &[0, 0, 1]);
+/// assert_eq!(csc.values(), &[1, 2, 3]);
+/// 
+/// assert_eq!(&csc * &vector![1, 1, 1], vector![3, 3]);
+/// assert_eq!(Matrix::from(&csc), matrix);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    rows : usize,
+    cols : usize,
+    data : Compressed<T>
+}
+
+impl<T> CscMatrix<T> {
+    pub(crate) fn from_parts(rows: usize, cols: usize, data: Compressed<T>) -> CscMatrix<T> {
+        CscMatrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    /// Returns the offsets into [row_indices][CscMatrix::row_indices] and
+    /// [values][CscMatrix::values] at which each column starts, followed by [nnz][CscMatrix::nnz].
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    /// Returns the row index of every stored entry, column by column.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    /// Returns the value of every stored entry, column by column.
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// Returns the row indices and values stored in a column.
+    /// 
+    /// # Panic!
+    /// This function will panic if the column is out of range.
+    pub fn col(&self, col: usize) -> (&[usize], &[T]) {
+        if col >= self.cols {
+            panic!("Column index out of range for the matrix.")
+        }
+
+        self.data.line(col)
+    }
+
+    /// Borrows the entry at the given row and column, or returns [None]
+    /// if no entry is stored there or either index is out of range.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        self.data.get(col, row)
+    }
+
+    /// Returns the transpose of the matrix, still in CSC format.
+    pub fn transpose(&self) -> CscMatrix<T>
+    where
+        T: Zero + Copy
+    {
+        CscMatrix::from_parts(self.cols, self.rows, self.data.transpose(self.rows))
+    }
+
+    /// Converts into compressed sparse row format.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::sparse::{CsrMatrix, CscMatrix};
+    /// 
+    /// let matrix = matrix![[1, 0, 2],
+    ///                      [0, 3, 0]];
+    /// 
+    /// assert_eq!(CscMatrix::from(&matrix).to_csr(), CsrMatrix::from(&matrix));
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Zero + Copy
+    {
+        CsrMatrix::from_parts(self.rows, self.cols, self.data.transpose(self.rows))
+    }
+}
+
+/// Converts a [Matrix] into a [CscMatrix], keeping only the nonzero entries.
+impl<T> From<&Matrix<T>> for CscMatrix<T>
+where
+    T: Zero + PartialEq + Copy
+{
+    fn from(matrix: &Matrix<T>) -> Self {
+        CscMatrix::from_parts(
+            matrix.rows(),
+            matrix.cols(),
+            Compressed::from_dense(matrix.cols(), matrix.rows(), |col, row| matrix[(row, col)])
+        )
+    }
+}
+
+/// Converts a [CscMatrix] into a dense [Matrix].
+impl<T> From<&CscMatrix<T>> for Matrix<T>
+where
+    T: Zero + Clone
+{
+    fn from(csc: &CscMatrix<T>) -> Self {
+        let mut matrix = Matrix::zeros(csc.rows, csc.cols);
+
+        for col in 0..csc.cols {
+            let (indices, values) = csc.data.line(col);
+
+            for (row, value) in indices.iter().zip(values) {
+                matrix[(*row, col)] = value.clone()
+            }
+        }
+
+        matrix
+    }
+}
+
+//
+//
+//          CscMatrix + CscMatrix Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for '&CscMatrix + &CscMatrix'.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add for &CscMatrix<T>
+where
+    T: Ring
+{
+    type Output = CscMatrix<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be added together.")
+        }
+
+        CscMatrix::from_parts(self.rows, self.cols, self.data.add(&rhs.data))
+    }
+}
+
+//
+//
+//          CscMatrix * Vector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&CscMatrix * &Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &CscMatrix<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if self.cols != rhs.len() {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        let mut params = vec![T::zero(); self.rows];
+
+        // Each column of the matrix is scaled by its vector element and
+        // scattered into the output.
+        for (col, scale) in rhs.list().iter().enumerate() {
+            let (indices, values) = self.data.line(col);
+
+            for (row, value) in indices.iter().zip(values) {
+                params[*row] += *value * *scale
+            }
+        }
+
+        Vector::from(params)
+    }
+}
+
+//
+//
+//          CscMatrix * Matrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&CscMatrix * &Matrix'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the sparse matrix
+/// is not equal to the number of rows in the dense matrix.
+impl<T> Mul<&Matrix<T>> for &CscMatrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.cols != rhs.rows() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        let out_cols = rhs.cols();
+        let mut params = vec![T::zero(); self.rows * out_cols];
+
+        // Column `col` of the sparse matrix pairs with row `col` of the rhs.
+        for col in 0..self.cols {
+            let (indices, values) = self.data.line(col);
+            let rhs_row = rhs.row(col);
+
+            for (row, value) in indices.iter().zip(values) {
+                let out_row = &mut params[row * out_cols..(row + 1) * out_cols];
+
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs_row) {
+                    *param += *value * *rhs_value
+                }
+            }
+        }
+
+        Matrix::from_parts(self.rows, out_cols, params)
+    }
+}
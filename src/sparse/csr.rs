@@ -0,0 +1,306 @@
+use std::ops::{Add, Mul};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::sparse::{Compressed, CscMatrix};
+use crate::traits::{Zero, Ring};
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+/// 
+/// The entries of each row are stored contiguously and sorted by column,
+/// which makes row access and the product with a [Vector] cheap. Build one
+/// from a [CooMatrix][crate::sparse::CooMatrix] or convert it from a dense [Matrix].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::sparse::CsrMatrix;
+/// 
+/// let matrix = matrix![[1, 0, 2],
+///                      [0, 0, 3]];
+/// 
+/// let csr = CsrMatrix::from(&matrix);
+/// 
+/// assert_eq!(csr.nnz(), 3);
+/// assert_eq!(csr.row_offsets(), &[0, 2, 3]);
+/// assert_eq!(csr.col_indices(), &[0, 2, 2]);
+/// assert_eq!(csr.values(), &[1, 2, 3]);
+/// 
+/// assert_eq!(&csr * &vector![1, 1, 1], vector![3, 3]);
+/// assert_eq!(Matrix::from(&csr), matrix);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    rows : usize,
+    cols : usize,
+    data : Compressed<T>
+}
+
+impl<T> CsrMatrix<T> {
+    pub(crate) fn from_parts(rows: usize, cols: usize, data: Compressed<T>) -> CsrMatrix<T> {
+        CsrMatrix {
+            rows,
+            cols,
+            data
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    /// Returns the offsets into [col_indices][CsrMatrix::col_indices] and
+    /// [values][CsrMatrix::values] at which each row starts, followed by [nnz][CsrMatrix::nnz].
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    /// Returns the column index of every stored entry, row by row.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    /// Returns the value of every stored entry, row by row.
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// Returns the column indices and values stored in a row.
+    /// 
+    /// # Panic!
+    /// This function will panic if the row is out of range.
+    pub fn row(&self, row: usize) -> (&[usize], &[T]) {
+        if row >= self.rows {
+            panic!("Row index out of range for the matrix.")
+        }
+
+        self.data.line(row)
+    }
+
+    /// Borrows the entry at the given row and column, or returns [None]
+    /// if no entry is stored there or either index is out of range.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::sparse::CsrMatrix;
+    /// 
+    /// let csr = CsrMatrix::from(&matrix![[1, 0],
+    ///                                    [0, 2]]);
+    /// 
+    /// assert_eq!(csr.get(1, 1), Some(&2));
+    /// assert_eq!(csr.get(0, 1), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None
+        }
+
+        self.data.get(row, col)
+    }
+
+    /// Returns the transpose of the matrix, still in CSR format.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::sparse::CsrMatrix;
+    /// 
+    /// let matrix = matrix![[1, 0, 2],
+    ///                      [0, 3, 0]];
+    /// 
+    /// let transpose = CsrMatrix::from(&matrix).transpose();
+    /// 
+    /// assert_eq!(Matrix::from(&transpose), matrix.transpose());
+    /// ```
+    pub fn transpose(&self) -> CsrMatrix<T>
+    where
+        T: Zero + Copy
+    {
+        CsrMatrix::from_parts(self.cols, self.rows, self.data.transpose(self.cols))
+    }
+
+    /// Converts into compressed sparse column format.
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Zero + Copy
+    {
+        CscMatrix::from_parts(self.rows, self.cols, self.data.transpose(self.cols))
+    }
+}
+
+/// Converts a [Matrix] into a [CsrMatrix], keeping only the nonzero entries.
+impl<T> From<&Matrix<T>> for CsrMatrix<T>
+where
+    T: Zero + PartialEq + Copy
+{
+    fn from(matrix: &Matrix<T>) -> Self {
+        CsrMatrix::from_parts(
+            matrix.rows(),
+            matrix.cols(),
+            Compressed::from_dense(matrix.rows(), matrix.cols(), |row, col| matrix[(row, col)])
+        )
+    }
+}
+
+/// Converts a [CsrMatrix] into a dense [Matrix].
+impl<T> From<&CsrMatrix<T>> for Matrix<T>
+where
+    T: Zero + Clone
+{
+    fn from(csr: &CsrMatrix<T>) -> Self {
+        let mut matrix = Matrix::zeros(csr.rows, csr.cols);
+
+        for row in 0..csr.rows {
+            let (indices, values) = csr.data.line(row);
+
+            for (col, value) in indices.iter().zip(values) {
+                matrix[(row, *col)] = value.clone()
+            }
+        }
+
+        matrix
+    }
+}
+
+//
+//
+//          CsrMatrix + CsrMatrix Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for '&CsrMatrix + &CsrMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::sparse::CsrMatrix;
+/// 
+/// let csr1 = CsrMatrix::from(&matrix![[1, 0],
+///                                     [0, 2]]);
+/// let csr2 = CsrMatrix::from(&matrix![[0, 3],
+///                                     [0, 4]]);
+/// 
+/// assert_eq!(Matrix::from(&(&csr1 + &csr2)), matrix![[1, 3],
+///                                                    [0, 6]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add for &CsrMatrix<T>
+where
+    T: Ring
+{
+    type Output = CsrMatrix<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            panic!("Differently sized matrices cannot be added together.")
+        }
+
+        CsrMatrix::from_parts(self.rows, self.cols, self.data.add(&rhs.data))
+    }
+}
+
+//
+//
+//          CsrMatrix * Vector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&CsrMatrix * &Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &CsrMatrix<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if self.cols != rhs.len() {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        let vector = rhs.list();
+        let mut params = Vec::with_capacity(self.rows);
+
+        for row in 0..self.rows {
+            let (indices, values) = self.data.line(row);
+            let mut sum = T::zero();
+
+            for (col, value) in indices.iter().zip(values) {
+                sum += *value * vector[*col]
+            }
+
+            params.push(sum)
+        }
+
+        Vector::from(params)
+    }
+}
+
+//
+//
+//          CsrMatrix * Matrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&CsrMatrix * &Matrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::sparse::CsrMatrix;
+/// 
+/// let csr = CsrMatrix::from(&matrix![[2, 0],
+///                                    [0, 0],
+///                                    [1, 3]]);
+/// 
+/// assert_eq!(&csr * &matrix![[1, 2],
+///                            [3, 4]], matrix![[2,  4],
+///                                             [0,  0],
+///                                             [10, 14]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the sparse matrix
+/// is not equal to the number of rows in the dense matrix.
+impl<T> Mul<&Matrix<T>> for &CsrMatrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.cols != rhs.rows() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        let out_cols = rhs.cols();
+        let mut params = vec![T::zero(); self.rows * out_cols];
+
+        // Each stored entry scales a whole row of the rhs into the output row.
+        for row in 0..self.rows {
+            let (indices, values) = self.data.line(row);
+            let out_row = &mut params[row * out_cols..(row + 1) * out_cols];
+
+            for (col, value) in indices.iter().zip(values) {
+                for (param, rhs_value) in out_row.iter_mut().zip(rhs.row(*col)) {
+                    *param += *value * *rhs_value
+                }
+            }
+        }
+
+        Matrix::from_parts(self.rows, out_cols, params)
+    }
+}
@@ -0,0 +1,210 @@
+mod coo;
+mod csr;
+mod csc;
+
+pub use crate::sparse::coo::CooMatrix;
+pub use crate::sparse::csr::CsrMatrix;
+pub use crate::sparse::csc::CscMatrix;
+
+use crate::traits::{Zero, Ring};
+
+/// The storage shared by [CsrMatrix] and [CscMatrix].
+/// 
+/// The entries of each major line (a row for CSR, a column for CSC) sit in
+/// `indices[offsets[major]..offsets[major + 1]]`, sorted by minor index and
+/// free of duplicates.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Compressed<T> {
+    pub(crate) offsets : Vec<usize>,
+    pub(crate) indices : Vec<usize>,
+    pub(crate) values : Vec<T>
+}
+
+impl<T> Compressed<T> {
+    /// Compresses a list of triplets, summing duplicate entries.
+    pub(crate) fn from_triplets(major_len: usize, majors: &[usize], minors: &[usize], values: &[T]) -> Compressed<T>
+    where
+        T: Ring
+    {
+        // Bucket the triplets by major index, keeping their insertion order.
+        let mut offsets = vec![0; major_len + 1];
+
+        for major in majors {
+            offsets[major + 1] += 1
+        }
+
+        for major in 0..major_len {
+            offsets[major + 1] += offsets[major]
+        }
+
+        let mut next = offsets.clone();
+        let mut order = vec![0; majors.len()];
+
+        for (entry, major) in majors.iter().enumerate() {
+            order[next[*major]] = entry;
+            next[*major] += 1
+        }
+
+        let mut compressed = Compressed {
+            offsets : Vec::with_capacity(major_len + 1),
+            indices : Vec::with_capacity(majors.len()),
+            values : Vec::with_capacity(majors.len())
+        };
+
+        compressed.offsets.push(0);
+
+        for major in 0..major_len {
+            let line = &mut order[offsets[major]..offsets[major + 1]];
+            let start = compressed.indices.len();
+
+            line.sort_by_key(|entry| minors[*entry]);
+
+            for entry in line.iter() {
+                if compressed.indices.len() > start && compressed.indices.last() == Some(&minors[*entry]) {
+                    *compressed.values.last_mut().unwrap() += values[*entry]
+                } else {
+                    compressed.indices.push(minors[*entry]);
+                    compressed.values.push(values[*entry])
+                }
+            }
+
+            compressed.offsets.push(compressed.indices.len())
+        }
+
+        compressed
+    }
+
+    /// Compresses the nonzero entries produced by `funct(major, minor)`.
+    pub(crate) fn from_dense<F>(major_len: usize, minor_len: usize, funct: F) -> Compressed<T>
+    where
+        T: Zero + PartialEq,
+        F: Fn(usize, usize) -> T
+    {
+        let mut compressed = Compressed {
+            offsets : Vec::with_capacity(major_len + 1),
+            indices : Vec::new(),
+            values : Vec::new()
+        };
+
+        compressed.offsets.push(0);
+
+        for major in 0..major_len {
+            for minor in 0..minor_len {
+                let value = funct(major, minor);
+
+                if value != T::zero() {
+                    compressed.indices.push(minor);
+                    compressed.values.push(value)
+                }
+            }
+
+            compressed.offsets.push(compressed.indices.len())
+        }
+
+        compressed
+    }
+
+    /// Returns the number of major lines.
+    pub(crate) fn major_len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the minor indices and values stored in a major line.
+    pub(crate) fn line(&self, major: usize) -> (&[usize], &[T]) {
+        let range = self.offsets[major]..self.offsets[major + 1];
+
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    /// Borrows the entry at `(major, minor)`, if one is stored.
+    pub(crate) fn get(&self, major: usize, minor: usize) -> Option<&T> {
+        let (indices, values) = self.line(major);
+
+        indices.binary_search(&minor)
+            .ok()
+            .map(|position| &values[position])
+    }
+
+    /// Swaps the roles of the major and minor axes, which turns CSR storage of
+    /// a matrix into CSC storage of the same matrix.
+    pub(crate) fn transpose(&self, minor_len: usize) -> Compressed<T>
+    where
+        T: Zero + Copy
+    {
+        let mut offsets = vec![0; minor_len + 1];
+
+        for minor in &self.indices {
+            offsets[minor + 1] += 1
+        }
+
+        for minor in 0..minor_len {
+            offsets[minor + 1] += offsets[minor]
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![T::zero(); self.values.len()];
+
+        // Walking the major lines in order leaves every new line sorted.
+        for major in 0..self.major_len() {
+            let (line_indices, line_values) = self.line(major);
+
+            for (minor, value) in line_indices.iter().zip(line_values) {
+                indices[next[*minor]] = major;
+                values[next[*minor]] = *value;
+                next[*minor] += 1
+            }
+        }
+
+        Compressed {
+            offsets,
+            indices,
+            values
+        }
+    }
+
+    /// Merges the entries of two equally shaped storages, summing
+    /// the entries both of them hold.
+    pub(crate) fn add(&self, other: &Compressed<T>) -> Compressed<T>
+    where
+        T: Ring
+    {
+        let mut compressed = Compressed {
+            offsets : Vec::with_capacity(self.offsets.len()),
+            indices : Vec::with_capacity(self.indices.len() + other.indices.len()),
+            values : Vec::with_capacity(self.values.len() + other.values.len())
+        };
+
+        compressed.offsets.push(0);
+
+        for major in 0..self.major_len() {
+            let (lhs_indices, lhs_values) = self.line(major);
+            let (rhs_indices, rhs_values) = other.line(major);
+            let (mut lhs, mut rhs) = (0, 0);
+
+            while lhs < lhs_indices.len() || rhs < rhs_indices.len() {
+                let lhs_minor = lhs_indices.get(lhs).copied().unwrap_or(usize::MAX);
+                let rhs_minor = rhs_indices.get(rhs).copied().unwrap_or(usize::MAX);
+
+                if lhs_minor == rhs_minor {
+                    compressed.indices.push(lhs_minor);
+                    compressed.values.push(lhs_values[lhs] + rhs_values[rhs]);
+                    lhs += 1;
+                    rhs += 1
+                } else if lhs_minor < rhs_minor {
+                    compressed.indices.push(lhs_minor);
+                    compressed.values.push(lhs_values[lhs]);
+                    lhs += 1
+                } else {
+                    compressed.indices.push(rhs_minor);
+                    compressed.values.push(rhs_values[rhs]);
+                    rhs += 1
+                }
+            }
+
+            compressed.offsets.push(compressed.indices.len())
+        }
+
+        compressed
+    }
+}
@@ -0,0 +1,5 @@
+mod svector;
+mod smatrix;
+
+pub use crate::static_impl::svector::SVector;
+pub use crate::static_impl::smatrix::SMatrix;
@@ -0,0 +1,524 @@
+use std::ops::{Add, Sub, Neg, Mul, Index, IndexMut};
+use crate::matrix_impl::Matrix;
+use crate::static_impl::SVector;
+use crate::error::{Shape, ShapeError};
+use crate::traits::{Zero, One, Ring};
+
+/// A stack-allocated matrix whose `R` rows and `C` columns are part of its type.
+/// 
+/// Operations between [SMatrix]s and [SVector]s only compile when their
+/// dimensions agree, so no shape checks are needed at runtime.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[1, 2],
+///                              [3, 4],
+///                              [5, 6]]);
+/// 
+/// let matrix2 = SMatrix::from([[1, 0, 2],
+///                              [0, 1, 3]]);
+/// 
+/// assert_eq!(matrix1 * matrix2, SMatrix::from([[1, 2,  8],
+///                                              [3, 4, 18],
+///                                              [5, 6, 28]]));
+/// ```
+/// Multiplying a 3x4 matrix by another 3x4 matrix is a compile error.
+/// ```compile_fail
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1: SMatrix<i32, 3, 4> = SMatrix::zeros();
+/// let matrix2: SMatrix<i32, 3, 4> = SMatrix::zeros();
+/// 
+/// let matrix3 = matrix1 * matrix2;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    array : [[T; C]; R]
+}
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// Constructs an SMatrix by calling `funct` with each row and column index.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix: SMatrix<usize, 2, 3> = SMatrix::from_fn(|row, col| row * 3 + col);
+    /// 
+    /// assert_eq!(matrix, SMatrix::from([[0, 1, 2],
+    ///                                   [3, 4, 5]]));
+    /// ```
+    pub fn from_fn<F>(mut funct: F) -> SMatrix<T, R, C>
+    where
+        F: FnMut(usize, usize) -> T
+    {
+        SMatrix {
+            array : std::array::from_fn(|row| std::array::from_fn(|col| funct(row, col)))
+        }
+    }
+
+    /// Constructs an SMatrix filled with zeros.
+    pub fn zeros() -> SMatrix<T, R, C>
+    where
+        T: Zero
+    {
+        SMatrix::from_fn(|_, _| T::zero())
+    }
+
+    /// Returns the number of rows, which is always `R`.
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of columns, which is always `C`.
+    pub const fn cols(&self) -> usize {
+        C
+    }
+
+    /// Returns a reference to the underlying array of rows.
+    pub fn as_array(&self) -> &[[T; C]; R] {
+        &self.array
+    }
+
+    /// Consumes the SMatrix and returns the underlying array of rows.
+    pub fn into_array(self) -> [[T; C]; R] {
+        self.array
+    }
+
+    /// Returns an iterator over references to the elements in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.array.as_flattened().iter()
+    }
+
+    /// Returns the transpose of the SMatrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let matrix = SMatrix::from([[1, 2, 3],
+    ///                             [4, 5, 6]]);
+    /// 
+    /// assert_eq!(matrix.transpose(), SMatrix::from([[1, 4],
+    ///                                               [2, 5],
+    ///                                               [3, 6]]));
+    /// ```
+    pub fn transpose(&self) -> SMatrix<T, C, R>
+    where
+        T: Copy
+    {
+        SMatrix::from_fn(|row, col| self.array[col][row])
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N> {
+    /// Constructs the square identity SMatrix.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let identity: SMatrix<i32, 2, 2> = SMatrix::identity();
+    /// 
+    /// assert_eq!(identity, SMatrix::from([[1, 0],
+    ///                                     [0, 1]]));
+    /// ```
+    pub fn identity() -> SMatrix<T, N, N>
+    where
+        T: Zero + One
+    {
+        SMatrix::from_fn(|row, col| if row == col { T::one() } else { T::zero() })
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for SMatrix<T, R, C> {
+    fn from(array: [[T; C]; R]) -> Self {
+        SMatrix {
+            array
+        }
+    }
+}
+
+/// Converts an [SMatrix] into a heap-allocated [Matrix].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = Matrix::from(SMatrix::from([[1, 2],
+///                                          [3, 4]]));
+/// 
+/// assert_eq!(matrix, matrix![[1, 2],
+///                            [3, 4]]);
+/// ```
+impl<T, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T> {
+    fn from(matrix: SMatrix<T, R, C>) -> Self {
+        let params = matrix.array
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Matrix::from_parts(R, C, params)
+    }
+}
+
+/// Converts a [Matrix] into an [SMatrix], checking its shape at runtime.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::error::Shape;
+/// 
+/// let matrix: SMatrix<i32, 2, 2> = matrix![[1, 2],
+///                                          [3, 4]].try_into().unwrap();
+/// 
+/// assert_eq!(matrix[(1, 0)], 3);
+/// 
+/// let error = SMatrix::<i32, 3, 2>::try_from(matrix![[1, 2]]).unwrap_err();
+/// 
+/// assert_eq!(error.lhs(), Shape::Matrix(1, 2));
+/// assert_eq!(error.rhs(), Shape::Matrix(3, 2));
+/// ```
+/// 
+/// # Error
+/// Returns a [ShapeError] if the Matrix is not `R x C`.
+impl<T, const R: usize, const C: usize> TryFrom<Matrix<T>> for SMatrix<T, R, C> {
+    type Error = ShapeError;
+
+    fn try_from(matrix: Matrix<T>) -> Result<Self, Self::Error> {
+        if matrix.rows() != R || matrix.cols() != C {
+            return Err(ShapeError::new("convert", Shape::Matrix(matrix.rows(), matrix.cols()), Shape::Matrix(R, C)))
+        }
+
+        let mut params = matrix.into_parts().into_iter();
+
+        Ok(SMatrix {
+            array : std::array::from_fn(|_| {
+                std::array::from_fn(|_| params.next().expect("the matrix has R * C elements"))
+            })
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.array[row][col]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.array[row][col]
+    }
+}
+
+//
+//
+//          SMatrix + SMatrix Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for 'SMatrix + SMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// let matrix2 = SMatrix::from([[5, 6],
+///                              [7, 8]]);
+/// 
+/// assert_eq!(matrix1 + matrix2, SMatrix::from([[ 6,  8],
+///                                              [10, 12]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices of
+/// different sizes do not compile.
+impl<T, const R: usize, const C: usize> Add for SMatrix<T, R, C>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        SMatrix::from_fn(|row, col| self.array[row][col] + rhs.array[row][col])
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&SMatrix + &SMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// let matrix2 = SMatrix::from([[5, 6],
+///                              [7, 8]]);
+/// 
+/// assert_eq!(&matrix1 + &matrix2, SMatrix::from([[ 6,  8],
+///                                                [10, 12]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices of
+/// different sizes do not compile.
+impl<T, const R: usize, const C: usize> Add for &SMatrix<T, R, C>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for 'SMatrix - SMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[5, 6],
+///                              [7, 8]]);
+/// let matrix2 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// 
+/// assert_eq!(matrix1 - matrix2, SMatrix::from([[4, 4],
+///                                              [4, 4]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices of
+/// different sizes do not compile.
+impl<T, const R: usize, const C: usize> Sub for SMatrix<T, R, C>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SMatrix::from_fn(|row, col| self.array[row][col] - rhs.array[row][col])
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for '&SMatrix - &SMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[5, 6],
+///                              [7, 8]]);
+/// let matrix2 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// 
+/// assert_eq!(&matrix1 - &matrix2, SMatrix::from([[4, 4],
+///                                                [4, 4]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices of
+/// different sizes do not compile.
+impl<T, const R: usize, const C: usize> Sub for &SMatrix<T, R, C>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+/// The [negation][std::ops::Neg] implementation for '-SMatrix'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = SMatrix::from([[1, -2],
+///                             [3, -4]]);
+/// 
+/// assert_eq!(-matrix, SMatrix::from([[-1, 2],
+///                                    [-3, 4]]));
+/// ```
+impl<T, const R: usize, const C: usize> Neg for SMatrix<T, R, C>
+where
+    T: Neg<Output = T> + Copy
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn neg(self) -> Self::Output {
+        SMatrix::from_fn(|row, col| -self.array[row][col])
+    }
+}
+
+//
+//
+//          SMatrix * SMatrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for 'SMatrix<R, K> * SMatrix<K, C>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// let matrix2 = SMatrix::from([[0, 1],
+///                              [1, 0]]);
+/// 
+/// assert_eq!(matrix1 * matrix2, SMatrix::from([[2, 1],
+///                                              [4, 3]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices with
+/// mismatched inner dimensions do not compile.
+impl<T, const R: usize, const K: usize, const C: usize> Mul<SMatrix<T, K, C>> for SMatrix<T, R, K>
+where
+    T: Ring
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: SMatrix<T, K, C>) -> Self::Output {
+        let mut product = SMatrix::zeros();
+
+        // Accumulated in row-by-row (i-k-j) order, as for Matrix.
+        for (product_row, lhs_row) in product.array.iter_mut().zip(self.array.iter()) {
+            for (lhs_val, rhs_row) in lhs_row.iter().zip(rhs.array.iter()) {
+                for (val, rhs_val) in product_row.iter_mut().zip(rhs_row.iter()) {
+                    *val += *lhs_val * *rhs_val
+                }
+            }
+        }
+
+        product
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&SMatrix<R, K> * &SMatrix<K, C>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix1 = SMatrix::from([[1, 2],
+///                              [3, 4]]);
+/// let matrix2 = SMatrix::from([[0, 1],
+///                              [1, 0]]);
+/// 
+/// assert_eq!(&matrix1 * &matrix2, SMatrix::from([[2, 1],
+///                                                [4, 3]]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since matrices with
+/// mismatched inner dimensions do not compile.
+impl<T, const R: usize, const K: usize, const C: usize> Mul<&SMatrix<T, K, C>> for &SMatrix<T, R, K>
+where
+    T: Ring
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: &SMatrix<T, K, C>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+//
+//
+//          SMatrix * SVector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for 'SMatrix<R, C> * SVector<C>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = SMatrix::from([[1, 2, 3],
+///                             [4, 5, 6]]);
+/// 
+/// assert_eq!(matrix * SVector::from([1, 0, -1]), SVector::from([-2, -2]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since a vector whose length differs
+/// from the number of columns does not compile.
+impl<T, const R: usize, const C: usize> Mul<SVector<T, C>> for SMatrix<T, R, C>
+where
+    T: Ring
+{
+    type Output = SVector<T, R>;
+
+    fn mul(self, rhs: SVector<T, C>) -> Self::Output {
+        SVector::from_fn(|row| {
+            let mut sum = T::zero();
+
+            for (lhs, rhs) in self.array[row].iter().zip(rhs.array.iter()) {
+                sum += *lhs * *rhs
+            }
+
+            sum
+        })
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&SMatrix<R, C> * &SVector<C>'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = SMatrix::from([[1, 2, 3],
+///                             [4, 5, 6]]);
+/// let vector = SVector::from([1, 0, -1]);
+/// 
+/// assert_eq!(&matrix * &vector, SVector::from([-2, -2]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since a vector whose length differs
+/// from the number of columns does not compile.
+impl<T, const R: usize, const C: usize> Mul<&SVector<T, C>> for &SMatrix<T, R, C>
+where
+    T: Ring
+{
+    type Output = SVector<T, R>;
+
+    fn mul(self, rhs: &SVector<T, C>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'SMatrix * scalar'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let matrix = SMatrix::from([[1, 2],
+///                             [3, 4]]);
+/// 
+/// assert_eq!(matrix * 2, SMatrix::from([[2, 4],
+///                                       [6, 8]]));
+/// ```
+impl<T, const R: usize, const C: usize> Mul<T> for SMatrix<T, R, C>
+where
+    T: Ring
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        SMatrix::from_fn(|row, col| rhs * self.array[row][col])
+    }
+}
@@ -0,0 +1,375 @@
+use std::ops::{Add, Sub, Neg, Mul, Index, IndexMut};
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError};
+use crate::traits::{Zero, Ring};
+
+/// A stack-allocated vector whose length `N` is part of its type.
+/// 
+/// Operations between two [SVector]s only compile when their lengths agree,
+/// so no shape checks are needed at runtime.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5, 6]);
+/// 
+/// assert_eq!(vector1 + vector2, SVector::from([5, 7, 9]));
+/// assert_eq!(vector1 * vector2, 32);
+/// ```
+/// Adding vectors of different lengths is a compile error.
+/// ```compile_fail
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5]);
+/// 
+/// let vector3 = vector1 + vector2;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SVector<T, const N: usize> {
+    pub(crate) array : [T; N]
+}
+
+impl<T, const N: usize> SVector<T, N> {
+    /// Constructs an SVector by calling `funct` with each index.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let vector: SVector<usize, 4> = SVector::from_fn(|idx| idx * idx);
+    /// 
+    /// assert_eq!(vector, SVector::from([0, 1, 4, 9]));
+    /// ```
+    pub fn from_fn<F>(funct: F) -> SVector<T, N>
+    where
+        F: FnMut(usize) -> T
+    {
+        SVector {
+            array : std::array::from_fn(funct)
+        }
+    }
+
+    /// Constructs an SVector filled with zeros.
+    pub fn zeros() -> SVector<T, N>
+    where
+        T: Zero
+    {
+        SVector::from_fn(|_| T::zero())
+    }
+
+    /// Returns the length of the SVector, which is always `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if `N` is zero.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns a reference to the underlying array.
+    pub fn as_array(&self) -> &[T; N] {
+        &self.array
+    }
+
+    /// Consumes the SVector and returns the underlying array.
+    pub fn into_array(self) -> [T; N] {
+        self.array
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.array.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.array.iter_mut()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for SVector<T, N> {
+    fn from(array: [T; N]) -> Self {
+        SVector {
+            array
+        }
+    }
+}
+
+/// Converts an [SVector] into a heap-allocated [Vector].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector = Vector::from(SVector::from([1, 2, 3]));
+/// 
+/// assert_eq!(vector, vector![1, 2, 3]);
+/// ```
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T> {
+    fn from(vector: SVector<T, N>) -> Self {
+        Vector::from(Vec::from(vector.array))
+    }
+}
+
+/// Converts a [Vector] into an [SVector], checking its length at runtime.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::error::Shape;
+/// 
+/// let vector: SVector<i32, 3> = vector![1, 2, 3].try_into().unwrap();
+/// 
+/// assert_eq!(vector, SVector::from([1, 2, 3]));
+/// 
+/// let error = SVector::<i32, 2>::try_from(vector![1, 2, 3]).unwrap_err();
+/// 
+/// assert_eq!(error.lhs(), Shape::Vector(3));
+/// assert_eq!(error.rhs(), Shape::Vector(2));
+/// ```
+/// 
+/// # Error
+/// Returns a [ShapeError] if the length of the Vector is not `N`.
+impl<T, const N: usize> TryFrom<Vector<T>> for SVector<T, N> {
+    type Error = ShapeError;
+
+    fn try_from(vector: Vector<T>) -> Result<Self, Self::Error> {
+        let len = vector.len();
+
+        let array: [T; N] = vector.into_inner()
+            .try_into()
+            .map_err(|_| ShapeError::new("convert", Shape::Vector(len), Shape::Vector(N)))?;
+
+        Ok(SVector::from(array))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SVector<T, N> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.array[idx]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SVector<T, N> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.array[idx]
+    }
+}
+
+//
+//
+//          SVector + SVector Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for 'SVector + SVector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5, 6]);
+/// 
+/// assert_eq!(vector1 + vector2, SVector::from([5, 7, 9]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Add for SVector<T, N>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = SVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        SVector::from_fn(|idx| self.array[idx] + rhs.array[idx])
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&SVector + &SVector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5, 6]);
+/// 
+/// assert_eq!(&vector1 + &vector2, SVector::from([5, 7, 9]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Add for &SVector<T, N>
+where
+    T: Add<Output = T> + Copy
+{
+    type Output = SVector<T, N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for 'SVector - SVector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([4, 5, 6]);
+/// let vector2 = SVector::from([1, 2, 3]);
+/// 
+/// assert_eq!(vector1 - vector2, SVector::from([3, 3, 3]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Sub for SVector<T, N>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = SVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        SVector::from_fn(|idx| self.array[idx] - rhs.array[idx])
+    }
+}
+
+/// The [subtraction][std::ops::Sub] implementation for '&SVector - &SVector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([4, 5, 6]);
+/// let vector2 = SVector::from([1, 2, 3]);
+/// 
+/// assert_eq!(&vector1 - &vector2, SVector::from([3, 3, 3]));
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Sub for &SVector<T, N>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = SVector<T, N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+/// The [negation][std::ops::Neg] implementation for '-SVector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// assert_eq!(-SVector::from([1, -2, 3]), SVector::from([-1, 2, -3]));
+/// ```
+impl<T, const N: usize> Neg for SVector<T, N>
+where
+    T: Neg<Output = T> + Copy
+{
+    type Output = SVector<T, N>;
+
+    fn neg(self) -> Self::Output {
+        SVector::from_fn(|idx| -self.array[idx])
+    }
+}
+
+//
+//
+//          SVector * SVector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for 'SVector * SVector',
+/// which is the dot product.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5, 6]);
+/// 
+/// assert_eq!(vector1 * vector2, 32);
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Mul for SVector<T, N>
+where
+    T: Ring
+{
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut sum = T::zero();
+
+        for (lhs, rhs) in self.array.iter().zip(rhs.array.iter()) {
+            sum += *lhs * *rhs
+        }
+
+        sum
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&SVector * &SVector',
+/// which is the dot product.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let vector1 = SVector::from([1, 2, 3]);
+/// let vector2 = SVector::from([4, 5, 6]);
+/// 
+/// assert_eq!(&vector1 * &vector2, 32);
+/// ```
+/// 
+/// # Panic!
+/// This function never panics, since vectors of
+/// different lengths do not compile.
+impl<T, const N: usize> Mul for &SVector<T, N>
+where
+    T: Ring
+{
+    type Output = T;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'SVector * scalar'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// assert_eq!(SVector::from([1, 2, 3]) * 2, SVector::from([2, 4, 6]));
+/// ```
+impl<T, const N: usize> Mul<T> for SVector<T, N>
+where
+    T: Ring
+{
+    type Output = SVector<T, N>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        SVector::from_fn(|idx| rhs * self.array[idx])
+    }
+}
@@ -0,0 +1,284 @@
+use std::ops::Mul;
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError, LinalgError};
+use crate::traits::{Zero, Ring, Real};
+
+/// A square band matrix, storing only the diagonals within `lower` places
+/// below and `upper` places above the main diagonal.
+/// 
+/// Storage, products and [solves][Banded::solve] all scale with
+/// `size * (lower + upper + 1)` rather than `size * size`.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::structured::Banded;
+/// 
+/// // A pentadiagonal matrix.
+/// let banded = Banded::from_fn(4, 2, 2, |row, col| if row == col { 6.0 } else { -1.0 });
+/// 
+/// assert_eq!(Matrix::from(&banded), matrix![[ 6.0, -1.0, -1.0,  0.0],
+///                                           [-1.0,  6.0, -1.0, -1.0],
+///                                           [-1.0, -1.0,  6.0, -1.0],
+///                                           [ 0.0, -1.0, -1.0,  6.0]]);
+/// assert_eq!(&banded * &vector![1.0, 1.0, 1.0, 1.0], vector![4.0, 3.0, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<T> {
+    size : usize,
+    lower : usize,
+    upper : usize,
+    // Row-major band storage: row `i` holds columns `i - lower..=i + upper`,
+    // with the slots falling outside the matrix left as zero.
+    band : Vec<T>
+}
+
+impl<T> Banded<T> {
+    /// Constructs a `size x size` band matrix, computing each element within the band
+    /// from its row and column.
+    pub fn from_fn<F>(size: usize, lower: usize, upper: usize, mut funct: F) -> Banded<T>
+    where
+        T: Zero,
+        F: FnMut(usize, usize) -> T
+    {
+        let width = lower + upper + 1;
+        let mut band = Vec::with_capacity(size * width);
+
+        for row in 0..size {
+            for offset in 0..width {
+                match (row + offset).checked_sub(lower) {
+                    Some(col) if col < size => band.push(funct(row, col)),
+                    _ => band.push(T::zero())
+                }
+            }
+        }
+
+        Banded {
+            size,
+            lower,
+            upper,
+            band
+        }
+    }
+
+    /// Constructs a `size x size` band matrix filled with zeros.
+    pub fn zeros(size: usize, lower: usize, upper: usize) -> Banded<T>
+    where
+        T: Zero
+    {
+        Banded::from_fn(size, lower, upper, |_, _| T::zero())
+    }
+
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of stored diagonals below the main diagonal.
+    pub fn lower_bandwidth(&self) -> usize {
+        self.lower
+    }
+
+    /// Returns the number of stored diagonals above the main diagonal.
+    pub fn upper_bandwidth(&self) -> usize {
+        self.upper
+    }
+
+    /// Returns the position of `(row, col)` in the band storage,
+    /// or [None] if it lies outside the matrix or the band.
+    fn position(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.size || col >= self.size || col + self.lower < row || col > row + self.upper {
+            return None
+        }
+
+        Some(row * (self.lower + self.upper + 1) + col + self.lower - row)
+    }
+
+    /// Borrows the element at the given row and column, or returns [None]
+    /// if it lies outside the matrix or the band.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::structured::Banded;
+    /// 
+    /// let banded = Banded::from_fn(3, 1, 0, |row, col| row * 3 + col);
+    /// 
+    /// assert_eq!(banded.get(2, 1), Some(&7));
+    /// assert_eq!(banded.get(1, 2), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.position(row, col).map(|idx| &self.band[idx])
+    }
+
+    /// Mutably borrows the element at the given row and column, or returns [None]
+    /// if it lies outside the matrix or the band.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.position(row, col).map(|idx| &mut self.band[idx])
+    }
+
+    /// Solves `A * x = b` for `x` by LU decomposition with partial pivoting,
+    /// restricted to the band.
+    /// 
+    /// Pivoting widens the upper band of `U` to `lower + upper` diagonals,
+    /// so the cost is `O(size * lower * (lower + upper))`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::structured::Banded;
+    /// 
+    /// // The zero in the top left corner needs a row swap.
+    /// let banded = Banded::from_fn(3, 1, 1, |row, col| if row + col == 0 { 0.0 } else { 1.0 });
+    /// 
+    /// assert_eq!(banded.solve(&vector![2.0, 6.0, 5.0]), Ok(vector![1.0, 2.0, 3.0]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError>
+    where
+        T: Real
+    {
+        let size = self.size;
+
+        if rhs.len() != size {
+            return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Vector(rhs.len())).into())
+        }
+
+        let (lower, upper) = (self.lower, self.upper);
+        let width = 2 * lower + upper + 1;
+
+        // Work storage with room for the fill-in: row `i` holds
+        // columns `i - lower..=i + lower + upper`.
+        let mut work = vec![T::zero(); size * width];
+        let mut max_abs = T::zero();
+
+        for row in 0..size {
+            let stored = &self.band[row * (lower + upper + 1)..(row + 1) * (lower + upper + 1)];
+
+            for (offset, val) in stored.iter().enumerate() {
+                work[row * width + offset] = *val;
+
+                if val.abs() > max_abs {
+                    max_abs = val.abs()
+                }
+            }
+        }
+
+        let at = |row: usize, col: usize| row * width + col + lower - row;
+
+        // Pivots at or below this magnitude are treated as zero.
+        let tolerance = T::epsilon() * T::from_f64(size as f64) * max_abs;
+        let mut params = rhs.list().clone();
+
+        for pivot_idx in 0..size {
+            let last_row = (pivot_idx + lower).min(size - 1);
+            let last_col = (pivot_idx + lower + upper).min(size - 1);
+
+            let mut max_row = pivot_idx;
+            for row in pivot_idx + 1..=last_row {
+                if work[at(row, pivot_idx)].abs() > work[at(max_row, pivot_idx)].abs() {
+                    max_row = row
+                }
+            }
+
+            if max_row != pivot_idx {
+                for col in pivot_idx..=last_col {
+                    work.swap(at(pivot_idx, col), at(max_row, col))
+                }
+
+                params.swap(pivot_idx, max_row)
+            }
+
+            let pivot = work[at(pivot_idx, pivot_idx)];
+
+            if pivot.abs() <= tolerance {
+                return Err(LinalgError::Singular)
+            }
+
+            for row in pivot_idx + 1..=last_row {
+                let factor = work[at(row, pivot_idx)] / pivot;
+
+                for col in pivot_idx + 1..=last_col {
+                    let value = work[at(pivot_idx, col)];
+                    work[at(row, col)] -= factor * value
+                }
+
+                let solved = params[pivot_idx];
+                params[row] -= factor * solved
+            }
+        }
+
+        // Back substitution through the widened upper band.
+        for row in (0..size).rev() {
+            let last_col = (row + lower + upper).min(size - 1);
+            let mut param = params[row];
+
+            for col in row + 1..=last_col {
+                param -= work[at(row, col)] * params[col]
+            }
+
+            params[row] = param / work[at(row, row)]
+        }
+
+        Ok(Vector::from(params))
+    }
+}
+
+/// Converts a [Banded] matrix into a dense [Matrix].
+impl<T> From<&Banded<T>> for Matrix<T>
+where
+    T: Zero + Clone
+{
+    fn from(banded: &Banded<T>) -> Self {
+        Matrix::from_fn(banded.size, banded.size, |row, col| {
+            banded.get(row, col)
+                .cloned()
+                .unwrap_or_else(T::zero)
+        })
+    }
+}
+
+//
+//
+//          Banded * Vector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&Banded * &Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &Banded<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if self.size != rhs.len() {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        let width = self.lower + self.upper + 1;
+        let vector = rhs.list();
+        let mut params = Vec::with_capacity(self.size);
+
+        for row in 0..self.size {
+            let first_col = row.saturating_sub(self.lower);
+            let last_col = (row + self.upper).min(self.size - 1);
+            let stored = &self.band[row * width + first_col + self.lower - row..];
+            let mut sum = T::zero();
+
+            for (value, param) in stored.iter().zip(&vector[first_col..=last_col]) {
+                sum += *value * *param
+            }
+
+            params.push(sum)
+        }
+
+        Vector::from(params)
+    }
+}
@@ -0,0 +1,599 @@
+use std::ops::{Add, Mul};
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::LinalgError;
+use crate::traits::{Zero, Ring, Field};
+
+/// A square diagonal matrix, storing only its diagonal.
+/// 
+/// Multiplying a [Matrix] by a Diagonal from the left scales its rows,
+/// and from the right scales its columns, both in `O(rows * cols)` time.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::structured::Diagonal;
+/// 
+/// let diagonal = Diagonal::new(vector![1, 10]);
+/// let matrix = matrix![[1, 2],
+///                      [3, 4]];
+/// 
+/// assert_eq!(&diagonal * &matrix, matrix![[1,  2],
+///                                         [30, 40]]);
+/// assert_eq!(&matrix * &diagonal, matrix![[1, 20],
+///                                         [3, 40]]);
+/// assert_eq!(&diagonal * &vector![5, 6], vector![5, 60]);
+/// assert_eq!(diagonal.determinant(), 10);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagonal<T> {
+    diagonal : Vector<T>
+}
+
+impl<T> Diagonal<T> {
+    /// Constructs a diagonal matrix from its diagonal, listed from the top left.
+    pub fn new(diagonal: Vector<T>) -> Diagonal<T> {
+        Diagonal {
+            diagonal
+        }
+    }
+
+    /// Constructs the `size x size` identity matrix.
+    pub fn identity(size: usize) -> Diagonal<T>
+    where
+        T: Ring
+    {
+        Diagonal::new(Vector::from(vec![T::one(); size]))
+    }
+
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// Borrows the diagonal.
+    pub fn diagonal(&self) -> &Vector<T> {
+        &self.diagonal
+    }
+
+    /// Consumes the diagonal matrix and returns its diagonal.
+    pub fn into_vector(self) -> Vector<T> {
+        self.diagonal
+    }
+
+    /// Returns the determinant, which is the product of the diagonal.
+    pub fn determinant(&self) -> T
+    where
+        T: Ring
+    {
+        let mut det = T::one();
+
+        for val in self.diagonal.iter() {
+            det *= *val
+        }
+
+        det
+    }
+
+    /// Computes the inverse, by taking the reciprocal of each diagonal element.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::structured::Diagonal;
+    /// 
+    /// let diagonal = Diagonal::new(vector![2.0, 4.0]);
+    /// 
+    /// assert_eq!(diagonal.inverse(), Ok(Diagonal::new(vector![0.5, 0.25])));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Singular] if any diagonal element is zero.
+    pub fn inverse(&self) -> Result<Diagonal<T>, LinalgError>
+    where
+        T: Field
+    {
+        if self.diagonal.iter().any(|val| val.is_zero()) {
+            return Err(LinalgError::Singular)
+        }
+
+        Ok(Diagonal::new(self.diagonal.lambda(|val| T::one() / *val)))
+    }
+}
+
+impl<T> From<Vector<T>> for Diagonal<T> {
+    fn from(diagonal: Vector<T>) -> Self {
+        Diagonal::new(diagonal)
+    }
+}
+
+/// Converts a [Diagonal] matrix into a dense [Matrix].
+impl<T> From<&Diagonal<T>> for Matrix<T>
+where
+    T: Zero + Clone
+{
+    fn from(diagonal: &Diagonal<T>) -> Self {
+        Matrix::from_diagonal(&diagonal.diagonal)
+    }
+}
+
+//
+//
+//          Diagonal * Vector/Matrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * &Vector',
+/// which scales each element of the vector.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if self.size() != rhs.len() {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        self.diagonal.lambda_index(|idx| self.diagonal[idx] * rhs[idx])
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<Vector<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * &Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<Vector<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * &Matrix',
+/// which scales each row of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the size of the diagonal matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<&Matrix<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.size() != rhs.rows() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        rhs.lambda_index(|row, col| self.diagonal[row] * rhs[(row, col)])
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * Matrix',
+/// which scales each row of the matrix.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::structured::Diagonal;
+/// 
+/// let diagonal = Diagonal::new(vector![2, 3]);
+/// 
+/// // The owned product reuses the buffer of the scaled matrix.
+/// assert_eq!(&diagonal * Matrix::identity(2), matrix![[2, 0],
+///                                                     [0, 3]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the size of the diagonal matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<Matrix<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, mut rhs: Matrix<T>) -> Self::Output {
+        if self.size() != rhs.rows() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        let cols = rhs.cols();
+
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (idx, val) in rhs.as_mut_slice().iter_mut().enumerate() {
+            *val = self.diagonal[idx / cols] * *val
+        }
+
+        rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * &Matrix',
+/// which scales each row of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the size of the diagonal matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<&Matrix<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * Matrix',
+/// which scales each row of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the size of the diagonal matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<Matrix<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Matrix * &Diagonal',
+/// which scales each column of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// matrix is not equal to the size of the diagonal matrix.
+impl<T> Mul<&Diagonal<T>> for &Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Diagonal<T>) -> Self::Output {
+        if self.cols() != rhs.size() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        self.lambda_index(|row, col| self[(row, col)] * rhs.diagonal[col])
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Matrix * &Diagonal',
+/// which scales each column of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// matrix is not equal to the size of the diagonal matrix.
+impl<T> Mul<&Diagonal<T>> for Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: &Diagonal<T>) -> Self::Output {
+        if self.cols() != rhs.size() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        let cols = self.cols();
+
+        // The left hand side is owned, so its buffer is reused for the result.
+        for (idx, val) in self.as_mut_slice().iter_mut().enumerate() {
+            *val *= rhs.diagonal[idx % cols]
+        }
+
+        self
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Matrix * Diagonal',
+/// which scales each column of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// matrix is not equal to the size of the diagonal matrix.
+impl<T> Mul<Diagonal<T>> for &Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Diagonal<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Matrix * Diagonal',
+/// which scales each column of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the number of columns in the left hand side
+/// matrix is not equal to the size of the diagonal matrix.
+impl<T> Mul<Diagonal<T>> for Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Diagonal<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * &Diagonal'.
+/// 
+/// # Panic!
+/// This function will panic if the diagonal matrices are not equivalent in size.
+impl<T> Mul for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Diagonal<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.size() != rhs.size() {
+            panic!("The left matrix row count is not equal to the right matrix column count.")
+        }
+
+        Diagonal::new(self.diagonal.lambda_index(|idx| self.diagonal[idx] * rhs.diagonal[idx]))
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&Diagonal * Diagonal'.
+/// 
+/// # Panic!
+/// This function will panic if the diagonal matrices are not equivalent in size.
+impl<T> Mul<Diagonal<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Diagonal<T>;
+
+    fn mul(self, rhs: Diagonal<T>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * &Diagonal'.
+/// 
+/// # Panic!
+/// This function will panic if the diagonal matrices are not equivalent in size.
+impl<T> Mul<&Diagonal<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Diagonal<T>;
+
+    fn mul(self, rhs: &Diagonal<T>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for 'Diagonal * Diagonal'.
+/// 
+/// # Panic!
+/// This function will panic if the diagonal matrices are not equivalent in size.
+impl<T> Mul for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Diagonal<T>;
+
+    fn mul(self, rhs: Diagonal<T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+//
+//
+//          Diagonal + Matrix Implementations
+//
+//
+/// The [addition][std::ops::Add] implementation for '&Diagonal + &Matrix',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::structured::Diagonal;
+/// 
+/// // Shifting a matrix by λI.
+/// let shift = Diagonal::new(vector![0.5, 0.5]);
+/// 
+/// assert_eq!(&shift + &matrix![[1.0, 2.0],
+///                              [3.0, 4.0]], matrix![[1.5, 2.0],
+///                                                   [3.0, 4.5]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<&Matrix<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        if self.size() != rhs.rows() || self.size() != rhs.cols() {
+            panic!("Differently sized matrices cannot be added together.")
+        }
+
+        let mut matrix = rhs.clone();
+
+        for (idx, val) in self.diagonal.iter().enumerate() {
+            matrix[(idx, idx)] += *val
+        }
+
+        matrix
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&Diagonal + Matrix',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<Matrix<T>> for &Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, mut rhs: Matrix<T>) -> Self::Output {
+        if self.size() != rhs.rows() || self.size() != rhs.cols() {
+            panic!("Differently sized matrices cannot be added together.")
+        }
+
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (idx, val) in self.diagonal.iter().enumerate() {
+            rhs[(idx, idx)] += *val
+        }
+
+        rhs
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for 'Diagonal + &Matrix',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<&Matrix<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for 'Diagonal + Matrix',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<Matrix<T>> for Diagonal<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Self::Output {
+        &self + rhs
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&Matrix + &Diagonal',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<&Diagonal<T>> for &Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Diagonal<T>) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for 'Matrix + &Diagonal',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<&Diagonal<T>> for Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Diagonal<T>) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for '&Matrix + Diagonal',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<Diagonal<T>> for &Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Diagonal<T>) -> Self::Output {
+        &rhs + self
+    }
+}
+
+/// The [addition][std::ops::Add] implementation for 'Matrix + Diagonal',
+/// which only touches the diagonal of the matrix.
+/// 
+/// # Panic!
+/// This function will panic if the matrices are not equivalent in size.
+impl<T> Add<Diagonal<T>> for Matrix<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Diagonal<T>) -> Self::Output {
+        &rhs + self
+    }
+}
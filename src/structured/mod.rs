@@ -0,0 +1,9 @@
+mod banded;
+mod tridiagonal;
+mod triangular;
+mod diagonal;
+
+pub use crate::structured::banded::Banded;
+pub use crate::structured::tridiagonal::Tridiagonal;
+pub use crate::structured::triangular::{UpperTriangular, LowerTriangular};
+pub use crate::structured::diagonal::Diagonal;
@@ -0,0 +1,529 @@
+use std::ops::Mul;
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError, LinalgError};
+use crate::traits::{Zero, Ring, Real};
+
+/// A square upper triangular matrix, whose elements below the main
+/// diagonal are all zero.
+/// 
+/// Created by [Matrix::upper], which discards the elements below the diagonal.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let upper = matrix![[2.0, 1.0],
+///                     [4.0, 4.0]].upper().unwrap();
+/// 
+/// assert_eq!(upper.as_matrix(), &matrix![[2.0, 1.0],
+///                                        [0.0, 4.0]]);
+/// assert_eq!(upper.determinant(), 8.0);
+/// assert_eq!(upper.solve(&vector![4.0, 8.0]), Ok(vector![1.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T> {
+    matrix : Matrix<T>
+}
+
+/// A square lower triangular matrix, whose elements above the main
+/// diagonal are all zero.
+/// 
+/// Created by [Matrix::lower], which discards the elements above the diagonal.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let lower = matrix![[2.0, 9.0],
+///                     [1.0, 4.0]].lower().unwrap();
+/// 
+/// assert_eq!(lower.as_matrix(), &matrix![[2.0, 0.0],
+///                                        [1.0, 4.0]]);
+/// assert_eq!(lower.determinant(), 8.0);
+/// assert_eq!(lower.solve(&vector![2.0, 9.0]), Ok(vector![1.0, 2.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T> {
+    matrix : Matrix<T>
+}
+
+impl<T> Matrix<T>
+where
+    T: Zero + Clone
+{
+    /// Returns the upper triangle of a square matrix, including the diagonal,
+    /// with every element below the diagonal set to zero.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square.
+    pub fn upper(&self) -> Result<UpperTriangular<T>, LinalgError> {
+        if self.rows() != self.cols() {
+            return Err(LinalgError::NotSquare(self.rows(), self.cols()))
+        }
+
+        Ok(UpperTriangular {
+            matrix : Matrix::from_fn(self.rows(), self.cols(), |row, col| {
+                if row <= col { self[(row, col)].clone() } else { T::zero() }
+            })
+        })
+    }
+
+    /// Returns the lower triangle of a square matrix, including the diagonal,
+    /// with every element above the diagonal set to zero.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::NotSquare] if the matrix is not square.
+    pub fn lower(&self) -> Result<LowerTriangular<T>, LinalgError> {
+        if self.rows() != self.cols() {
+            return Err(LinalgError::NotSquare(self.rows(), self.cols()))
+        }
+
+        Ok(LowerTriangular {
+            matrix : Matrix::from_fn(self.rows(), self.cols(), |row, col| {
+                if row >= col { self[(row, col)].clone() } else { T::zero() }
+            })
+        })
+    }
+}
+
+impl<T> UpperTriangular<T> {
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Borrows the underlying dense [Matrix].
+    pub fn as_matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Consumes the triangular matrix and returns the underlying dense [Matrix].
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Returns the transpose, which is lower triangular.
+    pub fn transpose(&self) -> LowerTriangular<T>
+    where
+        T: Clone
+    {
+        LowerTriangular {
+            matrix : self.matrix.transpose()
+        }
+    }
+
+    /// Returns the determinant, which is the product of the diagonal.
+    pub fn determinant(&self) -> T
+    where
+        T: Ring
+    {
+        determinant(&self.matrix)
+    }
+
+    /// Solves `U * x = b` for `x` by back substitution.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError>
+    where
+        T: Real
+    {
+        solve(&self.matrix, rhs, Triangle::Upper)
+    }
+
+    /// Solves `U * X = B` for `X` by back substitution, treating each column
+    /// of `B` as a separate right hand side.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the number of rows in `B` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+    where
+        T: Real
+    {
+        solve_matrix(&self.matrix, rhs, Triangle::Upper)
+    }
+
+    /// Computes the inverse, which is also upper triangular.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let upper = matrix![[2.0, 1.0],
+    ///                     [0.0, 4.0]].upper().unwrap();
+    /// 
+    /// assert_eq!(upper.inverse().unwrap().as_matrix(), &matrix![[0.5, -0.125],
+    ///                                                           [0.0,  0.25]]);
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Singular] if the matrix is singular.
+    pub fn inverse(&self) -> Result<UpperTriangular<T>, LinalgError>
+    where
+        T: Real
+    {
+        Ok(UpperTriangular {
+            matrix : self.solve_matrix(&Matrix::identity(self.size()))?
+        })
+    }
+}
+
+impl<T> LowerTriangular<T> {
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Borrows the underlying dense [Matrix].
+    pub fn as_matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Consumes the triangular matrix and returns the underlying dense [Matrix].
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Returns the transpose, which is upper triangular.
+    pub fn transpose(&self) -> UpperTriangular<T>
+    where
+        T: Clone
+    {
+        UpperTriangular {
+            matrix : self.matrix.transpose()
+        }
+    }
+
+    /// Returns the determinant, which is the product of the diagonal.
+    pub fn determinant(&self) -> T
+    where
+        T: Ring
+    {
+        determinant(&self.matrix)
+    }
+
+    /// Solves `L * x = b` for `x` by forward substitution.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError>
+    where
+        T: Real
+    {
+        solve(&self.matrix, rhs, Triangle::Lower)
+    }
+
+    /// Solves `L * X = B` for `X` by forward substitution, treating each column
+    /// of `B` as a separate right hand side.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the number of rows in `B` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, LinalgError>
+    where
+        T: Real
+    {
+        solve_matrix(&self.matrix, rhs, Triangle::Lower)
+    }
+
+    /// Computes the inverse, which is also lower triangular.
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Singular] if the matrix is singular.
+    pub fn inverse(&self) -> Result<LowerTriangular<T>, LinalgError>
+    where
+        T: Real
+    {
+        Ok(LowerTriangular {
+            matrix : self.solve_matrix(&Matrix::identity(self.size()))?
+        })
+    }
+}
+
+impl<T> From<UpperTriangular<T>> for Matrix<T> {
+    fn from(upper: UpperTriangular<T>) -> Self {
+        upper.matrix
+    }
+}
+
+impl<T> From<LowerTriangular<T>> for Matrix<T> {
+    fn from(lower: LowerTriangular<T>) -> Self {
+        lower.matrix
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Triangle {
+    Upper,
+    Lower
+}
+
+impl Triangle {
+    /// Returns the columns of `row` that may be nonzero, excluding the diagonal.
+    fn off_diagonal(self, row: usize, size: usize) -> std::ops::Range<usize> {
+        match self {
+            Triangle::Upper => row + 1..size,
+            Triangle::Lower => 0..row
+        }
+    }
+
+    /// Returns the columns of `row` that may be nonzero, including the diagonal.
+    fn nonzero(self, row: usize, size: usize) -> std::ops::Range<usize> {
+        match self {
+            Triangle::Upper => row..size,
+            Triangle::Lower => 0..row + 1
+        }
+    }
+}
+
+fn determinant<T>(matrix: &Matrix<T>) -> T
+where
+    T: Ring
+{
+    let mut det = T::one();
+
+    for idx in 0..matrix.rows() {
+        det *= matrix[(idx, idx)]
+    }
+
+    det
+}
+
+/// Returns true if a diagonal element is zero, or too small relative
+/// to the largest element of the matrix to be solved reliably.
+fn is_singular<T>(matrix: &Matrix<T>) -> bool
+where
+    T: Real
+{
+    let size = matrix.rows();
+
+    let mut max_abs = T::zero();
+    for val in matrix.iter() {
+        if val.abs() > max_abs {
+            max_abs = val.abs()
+        }
+    }
+
+    let tolerance = T::epsilon() * T::from_f64(size as f64) * max_abs;
+
+    (0..size).any(|idx| matrix[(idx, idx)].abs() <= tolerance)
+}
+
+fn solve<T>(matrix: &Matrix<T>, rhs: &Vector<T>, triangle: Triangle) -> Result<Vector<T>, LinalgError>
+where
+    T: Real
+{
+    let size = matrix.rows();
+
+    if rhs.len() != size {
+        return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Vector(rhs.len())).into())
+    }
+
+    let mut params = rhs.list().clone();
+    substitute(matrix, &mut params, 1, triangle)?;
+
+    Ok(Vector::from(params))
+}
+
+fn solve_matrix<T>(matrix: &Matrix<T>, rhs: &Matrix<T>, triangle: Triangle) -> Result<Matrix<T>, LinalgError>
+where
+    T: Real
+{
+    let size = matrix.rows();
+
+    if rhs.rows() != size {
+        return Err(ShapeError::new("solve", Shape::Matrix(size, size), Shape::Matrix(rhs.rows(), rhs.cols())).into())
+    }
+
+    let mut params = rhs.as_slice().to_vec();
+    substitute(matrix, &mut params, rhs.cols(), triangle)?;
+
+    Ok(Matrix::from_parts(size, rhs.cols(), params))
+}
+
+/// Overwrites the row-major `params`, holding `cols` right hand sides,
+/// with the solution of the triangular system.
+fn substitute<T>(matrix: &Matrix<T>, params: &mut [T], cols: usize, triangle: Triangle) -> Result<(), LinalgError>
+where
+    T: Real
+{
+    if is_singular(matrix) {
+        return Err(LinalgError::Singular)
+    }
+
+    let size = matrix.rows();
+
+    for step in 0..size {
+        // Rows are solved in the order their dependencies become available:
+        // top down for a lower triangle and bottom up for an upper one.
+        let row = match triangle {
+            Triangle::Upper => size - 1 - step,
+            Triangle::Lower => step
+        };
+
+        for col in triangle.off_diagonal(row, size) {
+            let factor = matrix[(row, col)];
+
+            for idx in 0..cols {
+                let solved = params[col * cols + idx];
+                params[row * cols + idx] -= factor * solved
+            }
+        }
+
+        let pivot = matrix[(row, row)];
+        for param in &mut params[row * cols..(row + 1) * cols] {
+            *param /= pivot
+        }
+    }
+
+    Ok(())
+}
+
+fn mul_vector<T>(matrix: &Matrix<T>, rhs: &Vector<T>, triangle: Triangle) -> Vector<T>
+where
+    T: Ring
+{
+    let size = matrix.rows();
+
+    if size != rhs.len() {
+        panic!("The matrix column count must be equal to the vector parameter count.")
+    }
+
+    let vector = rhs.list();
+    let mut params = Vec::with_capacity(size);
+
+    for row in 0..size {
+        let cols = triangle.nonzero(row, size);
+        let mut sum = T::zero();
+
+        for (value, param) in matrix.row(row)[cols.clone()].iter().zip(&vector[cols]) {
+            sum += *value * *param
+        }
+
+        params.push(sum)
+    }
+
+    Vector::from(params)
+}
+
+fn mul_matrix<T>(matrix: &Matrix<T>, rhs: &Matrix<T>, triangle: Triangle) -> Matrix<T>
+where
+    T: Ring
+{
+    let size = matrix.rows();
+
+    if size != rhs.rows() {
+        panic!("The left matrix row count is not equal to the right matrix column count.")
+    }
+
+    let cols = rhs.cols();
+    let mut params = vec![T::zero(); size * cols];
+
+    for row in 0..size {
+        let out_row = &mut params[row * cols..(row + 1) * cols];
+
+        for index in triangle.nonzero(row, size) {
+            let lhs_value = matrix[(row, index)];
+
+            for (param, rhs_value) in out_row.iter_mut().zip(rhs.row(index)) {
+                *param += lhs_value * *rhs_value
+            }
+        }
+    }
+
+    Matrix::from_parts(size, cols, params)
+}
+
+//
+//
+//          UpperTriangular * Vector/Matrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&UpperTriangular * &Vector',
+/// which skips the zero elements below the diagonal.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &UpperTriangular<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        mul_vector(&self.matrix, rhs, Triangle::Upper)
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&UpperTriangular * &Matrix',
+/// which skips the zero elements below the diagonal.
+/// 
+/// # Panic!
+/// This function will panic if the size of the triangular matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<&Matrix<T>> for &UpperTriangular<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        mul_matrix(&self.matrix, rhs, Triangle::Upper)
+    }
+}
+
+//
+//
+//          LowerTriangular * Vector/Matrix Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&LowerTriangular * &Vector',
+/// which skips the zero elements above the diagonal.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &LowerTriangular<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        mul_vector(&self.matrix, rhs, Triangle::Lower)
+    }
+}
+
+/// The [multiplication][std::ops::Mul] implementation for '&LowerTriangular * &Matrix',
+/// which skips the zero elements above the diagonal.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// 
+/// let lower = matrix![[1, 0],
+///                     [2, 3]].lower().unwrap();
+/// 
+/// assert_eq!(&lower * &matrix![[1, 1],
+///                              [1, 2]], matrix![[1, 1],
+///                                               [5, 8]]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the size of the triangular matrix
+/// is not equal to the number of rows in the right hand side matrix.
+impl<T> Mul<&Matrix<T>> for &LowerTriangular<T>
+where
+    T: Ring
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        mul_matrix(&self.matrix, rhs, Triangle::Lower)
+    }
+}
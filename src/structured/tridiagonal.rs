@@ -0,0 +1,175 @@
+use std::ops::Mul;
+use crate::matrix_impl::Matrix;
+use crate::vector_impl::Vector;
+use crate::structured::Banded;
+use crate::error::LinalgError;
+use crate::traits::{Zero, Ring, Real};
+
+/// A square tridiagonal matrix, storing only its three diagonals.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::prelude::*;
+/// use simp_linalg::structured::Tridiagonal;
+/// 
+/// // The 1D finite-difference Laplacian.
+/// let laplacian = Tridiagonal::new(vector![-1.0, -1.0],
+///                                  vector![2.0, 2.0, 2.0],
+///                                  vector![-1.0, -1.0]);
+/// 
+/// assert_eq!(Matrix::from(&laplacian), matrix![[ 2.0, -1.0,  0.0],
+///                                              [-1.0,  2.0, -1.0],
+///                                              [ 0.0, -1.0,  2.0]]);
+/// 
+/// assert_eq!(&laplacian * &vector![1.0, 2.0, 3.0], vector![0.0, 0.0, 4.0]);
+/// 
+/// let solution = laplacian.solve(&vector![0.0, 0.0, 4.0]).unwrap();
+/// 
+/// for (param, expected) in solution.iter().zip([1.0_f64, 2.0, 3.0]) {
+///     assert!((param - expected).abs() < 1e-12)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<T> {
+    lower : Vec<T>,
+    diagonal : Vec<T>,
+    upper : Vec<T>
+}
+
+impl<T> Tridiagonal<T> {
+    /// Constructs a tridiagonal matrix from its subdiagonal, main diagonal
+    /// and superdiagonal, each listed from the top left.
+    /// 
+    /// # Panic!
+    /// This function will panic if `lower` and `upper` are not
+    /// one element shorter than `diagonal`.
+    pub fn new(lower: Vector<T>, diagonal: Vector<T>, upper: Vector<T>) -> Tridiagonal<T> {
+        let off_diagonal = diagonal.len().saturating_sub(1);
+
+        if lower.len() != off_diagonal || upper.len() != off_diagonal {
+            panic!("Off diagonals must be one element shorter than the main diagonal.")
+        }
+
+        Tridiagonal {
+            lower : lower.into_inner(),
+            diagonal : diagonal.into_inner(),
+            upper : upper.into_inner()
+        }
+    }
+
+    /// Returns the number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// Returns the subdiagonal, the elements at `(i + 1, i)`.
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /// Returns the main diagonal.
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    /// Returns the superdiagonal, the elements at `(i, i + 1)`.
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+
+    /// Solves `A * x = b` for `x` in `O(size)` time, by [banded][Banded::solve]
+    /// LU decomposition with partial pivoting.
+    /// 
+    /// Unlike the plain Thomas algorithm, the pivoting also handles systems
+    /// that are not diagonally dominant.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::structured::Tridiagonal;
+    /// 
+    /// let matrix = Tridiagonal::new(vector![1.0], vector![0.0, 0.0], vector![1.0]);
+    /// 
+    /// assert_eq!(matrix.solve(&vector![2.0, 3.0]), Ok(vector![3.0, 2.0]));
+    /// ```
+    /// 
+    /// # Error
+    /// Returns [LinalgError::Shape] if the length of `b` is not equal to
+    /// the size of the matrix, or [LinalgError::Singular] if the matrix is singular.
+    pub fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LinalgError>
+    where
+        T: Real
+    {
+        Banded::from(self).solve(rhs)
+    }
+}
+
+/// Converts a [Tridiagonal] matrix into a [Banded] matrix with one diagonal
+/// on either side of the main diagonal.
+impl<T> From<&Tridiagonal<T>> for Banded<T>
+where
+    T: Zero + Clone
+{
+    fn from(tridiagonal: &Tridiagonal<T>) -> Self {
+        Banded::from_fn(tridiagonal.size(), 1, 1, |row, col| {
+            if row == col {
+                tridiagonal.diagonal[row].clone()
+            } else if row > col {
+                tridiagonal.lower[col].clone()
+            } else {
+                tridiagonal.upper[row].clone()
+            }
+        })
+    }
+}
+
+/// Converts a [Tridiagonal] matrix into a dense [Matrix].
+impl<T> From<&Tridiagonal<T>> for Matrix<T>
+where
+    T: Zero + Clone
+{
+    fn from(tridiagonal: &Tridiagonal<T>) -> Self {
+        Matrix::from(&Banded::from(tridiagonal))
+    }
+}
+
+//
+//
+//          Tridiagonal * Vector Implementations
+//
+//
+/// The [multiplication][std::ops::Mul] implementation for '&Tridiagonal * &Vector'.
+/// 
+/// # Panic!
+/// This function will panic if the size of the matrix
+/// is not equal to the length of the vector.
+impl<T> Mul<&Vector<T>> for &Tridiagonal<T>
+where
+    T: Ring
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        if self.size() != rhs.len() {
+            panic!("The matrix column count must be equal to the vector parameter count.")
+        }
+
+        let vector = rhs.list();
+        let mut params = Vec::with_capacity(self.size());
+
+        for (row, diagonal) in self.diagonal.iter().enumerate() {
+            let mut sum = *diagonal * vector[row];
+
+            if row > 0 {
+                sum += self.lower[row - 1] * vector[row - 1]
+            }
+            if row + 1 < self.size() {
+                sum += self.upper[row] * vector[row + 1]
+            }
+
+            params.push(sum)
+        }
+
+        Vector::from(params)
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+/// The additive identity of a type.
+/// 
+/// This is used to seed accumulators, such as those in the dot product
+/// and matrix products, instead of relying on [Default].
+/// 
+/// It is implemented for every primitive integer and float type, and for
+/// [Complex][crate::complex::Complex]. The `num-traits` feature only adds
+/// [std::num::Wrapping], bridged from the num-traits crate, and the
+/// `num-complex` feature adds `num_complex::Complex`. Any other type
+/// needs its own implementation.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::traits::Zero;
+/// 
+/// assert_eq!(i32::zero(), 0);
+/// assert!(0.0_f64.is_zero());
+/// ```
+pub trait Zero: Sized + Add<Output = Self> {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns true if the value is the additive identity.
+    fn is_zero(&self) -> bool;
+}
+
+/// The multiplicative identity of a type.
+/// 
+/// It is implemented for the same types as [Zero].
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::traits::One;
+/// 
+/// assert_eq!(u8::one(), 1);
+/// assert_eq!(f32::one(), 1.0);
+/// ```
+pub trait One: Sized + Mul<Output = Self> {
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+}
+
+/// A type supporting addition, subtraction and multiplication,
+/// along with their identities.
+/// 
+/// This is the bound required by the products of vectors and matrices.
+/// It is implemented automatically for every type satisfying its supertraits.
+pub trait Ring:
+    Copy
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{}
+
+impl<T> Ring for T
+where
+    T: Copy
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + MulAssign
+{}
+
+/// A [Ring] that also supports exact division and negation.
+/// 
+/// Integers are deliberately excluded, since their division truncates.
+/// It is implemented for [f32] and [f64], and can be implemented for
+/// any user type satisfying its supertraits.
+pub trait Field: Ring + Div<Output = Self> + Neg<Output = Self> + DivAssign {}
+
+impl Field for f32 {}
+impl Field for f64 {}
+
+macro_rules! impl_identities {
+    ($zero:expr, $one:expr; $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_identities!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl_identities!(0.0, 1.0; f32, f64);
+
+// With the 'num-traits' feature enabled, the identities are bridged from the
+// num-traits crate for std::num::Wrapping, the only non-primitive type it
+// covers without pulling in another crate. Each type is listed explicitly
+// rather than through a blanket implementation, so that enabling the feature
+// cannot conflict with a downstream type implementing both traits.
+#[cfg(feature = "num-traits")]
+impl<T> Zero for std::num::Wrapping<T>
+where
+    std::num::Wrapping<T>: num_traits::Zero
+{
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        num_traits::Zero::is_zero(self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> One for std::num::Wrapping<T>
+where
+    std::num::Wrapping<T>: num_traits::One
+{
+    fn one() -> Self {
+        num_traits::One::one()
+    }
+}
+
+/// An ordered [Field] with the operations needed by the decompositions,
+/// such as pivoting on magnitudes and taking square roots.
+/// 
+/// It is implemented for [f32] and [f64].
+pub trait Real: Field + PartialOrd {
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+
+    /// Returns the natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Raises to a real power.
+    fn powf(self, exp: Self) -> Self;
+
+    /// Returns the four quadrant arctangent of `self / other`, in radians.
+    fn atan2(self, other: Self) -> Self;
+
+    /// Returns the difference between 1 and the next representable value.
+    fn epsilon() -> Self;
+
+    /// Converts a [f64] constant into this type.
+    fn from_f64(val: f64) -> Self;
+}
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                fn ln(self) -> Self {
+                    $t::ln(self)
+                }
+
+                fn powf(self, exp: Self) -> Self {
+                    $t::powf(self, exp)
+                }
+
+                fn atan2(self, other: Self) -> Self {
+                    $t::atan2(self, other)
+                }
+
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+
+                fn from_f64(val: f64) -> Self {
+                    val as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_real!(f32, f64);
+
+/// A [Field] with a complex conjugate, covering both real and complex numbers.
+/// 
+/// This is the bound required by conjugation and the Hermitian products,
+/// such as [Matrix::adjoint][crate::matrix_impl::Matrix::adjoint] and
+/// [Vector::inner][crate::vector_impl::Vector::inner]. For real numbers,
+/// conjugation does nothing and these reduce to the transpose and dot product.
+/// 
+/// It is implemented for [f32], [f64] and [Complex][crate::complex::Complex]
+/// over either of them. With the `num-complex` feature enabled, it is also
+/// implemented for `num_complex::Complex` over either of them.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::traits::ComplexField;
+/// use simp_linalg::complex::Complex;
+/// 
+/// assert_eq!(ComplexField::conjugate(2.0), 2.0);
+/// assert_eq!(ComplexField::conjugate(Complex::new(1.0, 2.0)), Complex::new(1.0, -2.0));
+/// assert_eq!(Complex::new(3.0, 4.0).modulus(), 5.0);
+/// ```
+pub trait ComplexField: Field {
+    /// The real type of the parts and the modulus.
+    type Real: Real;
+
+    /// Returns the complex conjugate.
+    fn conjugate(self) -> Self;
+
+    /// Returns the real part.
+    fn real(self) -> Self::Real;
+
+    /// Returns the imaginary part.
+    fn imaginary(self) -> Self::Real;
+
+    /// Returns the magnitude `|z|`.
+    fn modulus(self) -> Self::Real;
+
+    /// Converts a real number into this type.
+    fn from_real(val: Self::Real) -> Self;
+}
+
+macro_rules! impl_complex_field {
+    ($($t:ident),*) => {
+        $(
+            impl ComplexField for $t {
+                type Real = $t;
+
+                fn conjugate(self) -> Self {
+                    self
+                }
+
+                fn real(self) -> Self::Real {
+                    self
+                }
+
+                fn imaginary(self) -> Self::Real {
+                    0.0
+                }
+
+                fn modulus(self) -> Self::Real {
+                    $t::abs(self)
+                }
+
+                fn from_real(val: Self::Real) -> Self {
+                    val
+                }
+            }
+        )*
+    };
+}
+
+impl_complex_field!(f32, f64);
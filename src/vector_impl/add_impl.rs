@@ -91,19 +91,17 @@ where
 {
     type Output = Vector<T>;
 
-    fn add(self, rhs: Vector<T>) -> Self::Output {
+    fn add(self, mut rhs: Vector<T>) -> Self::Output {
         if self.len() != rhs.len() {
             panic!("Vectors with different sizes cannot be added together.")
         }
-        
-        let length = self.len();
 
-        let mut params = Vec::with_capacity(length);
-        for idx in 0..length {
-            params.push(self.list[idx] + rhs.list[idx])
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (lhs_val, rhs_val) in self.list.iter().zip(rhs.list.iter_mut()) {
+            *rhs_val = *lhs_val + *rhs_val
         }
 
-        Vector::from(params)
+        rhs
     }
 }
 
@@ -152,19 +150,10 @@ where
 {
     type Output = Vector<T>;
 
-    fn add(self, rhs: &Vector<T>) -> Self::Output {
-        if self.len() != rhs.len() {
-            panic!("Vectors with different sizes cannot be added together.")
-        }
-        
-        let length = self.len();
+    fn add(mut self, rhs: &Vector<T>) -> Self::Output {
+        self += rhs;
 
-        let mut params = Vec::with_capacity(length);
-        for idx in 0..length {
-            params.push(self.list[idx] + rhs.list[idx])
-        }
-
-        Vector::from(params)
+        self
     }
 }
 
@@ -213,18 +202,9 @@ where
 {
     type Output = Vector<T>;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        if self.len() != rhs.len() {
-            panic!("Vectors with different sizes cannot be added together.")
-        }
-        
-        let length = self.len();
-
-        let mut params = Vec::with_capacity(length);
-        for idx in 0..length {
-            params.push(self.list[idx] + rhs.list[idx])
-        }
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
 
-        Vector::from(params)
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,199 @@
+use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, MulAssign};
+use crate::vector_impl::Vector;
+
+//
+//
+//          VECTOR += VECTOR IMPLEMENTATIONS
+//
+//
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// The [addition assignment][std::ops::AddAssign] implementation for 'Vector += &Vector'.
+/// 
+/// The left hand side vector is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector1 = vector![1, 2, 3];
+/// let vector2 = vector![4, 5, 6];
+/// 
+/// vector1 += &vector2;
+/// 
+/// assert_eq!(vector1, vector![5, 7, 9])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> AddAssign<&Vector<T>> for Vector<T>
+where
+    T: Add<Output = T> + Copy
+{
+    fn add_assign(&mut self, rhs: &Vector<T>) {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be added together.")
+        }
+
+        for (lhs_val, rhs_val) in self.list.iter_mut().zip(rhs.list.iter()) {
+            *lhs_val = *lhs_val + *rhs_val
+        }
+    }
+}
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// The [addition assignment][std::ops::AddAssign] implementation for 'Vector += Vector'.
+/// 
+/// The left hand side vector is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector1 = vector![1, 2, 3];
+/// let vector2 = vector![4, 5, 6];
+/// 
+/// // The result of '&vector2 * 2' is an owned Vector,
+/// // which is then added to 'vector1'.
+/// vector1 += &vector2 * 2;
+/// 
+/// assert_eq!(vector1, vector![9, 12, 15])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> AddAssign for Vector<T>
+where
+    T: Add<Output = T> + Copy
+{
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self += &rhs
+    }
+}
+
+
+//
+//
+//          VECTOR -= VECTOR IMPLEMENTATIONS
+//
+//
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// The [subtraction assignment][std::ops::SubAssign] implementation for 'Vector -= &Vector'.
+/// 
+/// The left hand side vector is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// vector1 -= &vector2;
+/// 
+/// assert_eq!(vector1, vector![3, 3, 3])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> SubAssign<&Vector<T>> for Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    fn sub_assign(&mut self, rhs: &Vector<T>) {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be subtracted from each other.")
+        }
+
+        for (lhs_val, rhs_val) in self.list.iter_mut().zip(rhs.list.iter()) {
+            *lhs_val = *lhs_val - *rhs_val
+        }
+    }
+}
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// The [subtraction assignment][std::ops::SubAssign] implementation for 'Vector -= Vector'.
+/// 
+/// The left hand side vector is updated in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // The result of '&vector2 * 2' is an owned Vector,
+/// // which is then subtracted from 'vector1'.
+/// vector1 -= &vector2 * 2;
+/// 
+/// assert_eq!(vector1, vector![2, 1, 0])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> SubAssign for Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self -= &rhs
+    }
+}
+
+
+//
+//
+//          VECTOR *= SCALAR IMPLEMENTATION
+//
+//
+/// The [multiplication assignment][std::ops::MulAssign] implementation for 'Vector *= T'.
+/// 
+/// Each element of the vector is scaled in place, without allocating.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector = vector![1, 2, 3];
+/// 
+/// vector *= 3;
+/// 
+/// assert_eq!(vector, vector![3, 6, 9])
+/// ```
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: Copy + Mul<Output = T>
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for item in self.list.iter_mut() {
+            *item = rhs * *item
+        }
+    }
+}
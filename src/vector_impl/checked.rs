@@ -0,0 +1,84 @@
+use std::ops::{Add, Sub};
+use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError};
+use crate::traits::Ring;
+
+impl<T> Vector<T> {
+    /// Adds two vectors together, returning a [ShapeError] instead of
+    /// panicking when the vectors are not the same size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector1 = vector![1, 2, 3];
+    /// let vector2 = vector![4, 5, 6];
+    /// let vector3 = vector![7, 8];
+    /// 
+    /// assert_eq!(vector1.checked_add(&vector2), Ok(vector![5, 7, 9]));
+    /// assert!(vector1.checked_add(&vector3).is_err());
+    /// ```
+    pub fn checked_add(&self, other: &Vector<T>) -> Result<Vector<T>, ShapeError>
+    where
+        T: Add<Output = T> + Copy
+    {
+        if self.len() != other.len() {
+            return Err(ShapeError::new("add", Shape::Vector(self.len()), Shape::Vector(other.len())))
+        }
+
+        Ok(self + other)
+    }
+
+    /// Subtracts one vector from another, returning a [ShapeError] instead of
+    /// panicking when the vectors are not the same size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector1 = vector![4, 5, 6];
+    /// let vector2 = vector![1, 2, 3];
+    /// let vector3 = vector![7, 8];
+    /// 
+    /// assert_eq!(vector1.checked_sub(&vector2), Ok(vector![3, 3, 3]));
+    /// assert!(vector1.checked_sub(&vector3).is_err());
+    /// ```
+    pub fn checked_sub(&self, other: &Vector<T>) -> Result<Vector<T>, ShapeError>
+    where
+        T: Sub<Output = T> + Copy
+    {
+        if self.len() != other.len() {
+            return Err(ShapeError::new("sub", Shape::Vector(self.len()), Shape::Vector(other.len())))
+        }
+
+        Ok(self - other)
+    }
+
+    /// Calculates the dot product of two vectors, returning a [ShapeError]
+    /// instead of panicking when the vectors are not the same size.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector1 = vector![1, 2, 3];
+    /// let vector2 = vector![4, 5, 6];
+    /// let vector3 = vector![7, 8];
+    /// 
+    /// assert_eq!(vector1.checked_mul(&vector2), Ok(32));
+    /// assert!(vector1.checked_mul(&vector3).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &Vector<T>) -> Result<T, ShapeError>
+    where
+        T: Ring
+    {
+        if self.len() != other.len() {
+            return Err(ShapeError::new("mul", Shape::Vector(self.len()), Shape::Vector(other.len())))
+        }
+
+        Ok(self * other)
+    }
+}
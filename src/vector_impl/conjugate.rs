@@ -0,0 +1,57 @@
+use crate::vector_impl::Vector;
+use crate::traits::ComplexField;
+
+impl<T> Vector<T>
+where
+    T: ComplexField
+{
+    /// Returns the Vector with each element replaced by its complex conjugate.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let vector = vector![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+    /// 
+    /// assert_eq!(vector.conjugate(), vector![Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)]);
+    /// ```
+    pub fn conjugate(&self) -> Vector<T> {
+        self.lambda(|val| val.conjugate())
+    }
+
+    /// Computes the Hermitian inner product `⟨self, other⟩ = Σ conj(selfᵢ) * otherᵢ`.
+    /// 
+    /// Unlike the [dot product][std::ops::Mul], this conjugates the left hand side,
+    /// so that `⟨x, x⟩` is the real, non-negative squared length of `x`.
+    /// For real vectors the two agree.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use simp_linalg::complex::Complex;
+    /// 
+    /// let vector = vector![Complex::new(0.0, 1.0), Complex::new(1.0, 1.0)];
+    /// 
+    /// assert_eq!(vector.inner(&vector), Complex::new(3.0, 0.0));
+    /// 
+    /// // The bilinear dot product gives i² + (1 + i)² = -1 + 2i instead.
+    /// assert_eq!(&vector * &vector, Complex::new(-1.0, 2.0));
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the vectors are not the same size.
+    pub fn inner(&self, other: &Vector<T>) -> T {
+        if self.len() != other.len() {
+            panic!("Cannot find inner product of two differently sized vectors.")
+        }
+
+        let mut product = T::zero();
+
+        for (lhs, rhs) in self.iter().zip(other.iter()) {
+            product += lhs.conjugate() * *rhs
+        }
+
+        product
+    }
+}
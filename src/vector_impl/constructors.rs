@@ -0,0 +1,84 @@
+use crate::vector_impl::Vector;
+use crate::traits::{Zero, One};
+
+impl<T> Vector<T> {
+    /// Constructs a Vector of the given length filled with zeros.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector: Vector<i32> = Vector::zeros(3);
+    /// 
+    /// assert_eq!(vector, vector![0, 0, 0]);
+    /// ```
+    pub fn zeros(len: usize) -> Vector<T>
+    where
+        T: Zero + Clone
+    {
+        Vector::from(vec![T::zero(); len])
+    }
+
+    /// Constructs a Vector of the given length filled with ones.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector: Vector<f64> = Vector::ones(2);
+    /// 
+    /// assert_eq!(vector, vector![1.0, 1.0]);
+    /// ```
+    pub fn ones(len: usize) -> Vector<T>
+    where
+        T: One + Clone
+    {
+        Vector::from(vec![T::one(); len])
+    }
+
+    /// Constructs a Vector of the given length, computing
+    /// each element from its index.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector = Vector::from_fn(4, |idx| idx * idx);
+    /// 
+    /// assert_eq!(vector, vector![0, 1, 4, 9]);
+    /// ```
+    pub fn from_fn<F>(len: usize, funct: F) -> Vector<T>
+    where
+        F: FnMut(usize) -> T
+    {
+        Vector::from((0..len).map(funct).collect::<Vec<T>>())
+    }
+
+    /// Constructs a Vector by joining the given vectors end to end.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector = Vector::concat(&[&vector![1, 2], &vector![3], &vector![4, 5]]);
+    /// 
+    /// assert_eq!(vector, vector![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn concat(vectors: &[&Vector<T>]) -> Vector<T>
+    where
+        T: Clone
+    {
+        let len = vectors.iter().map(|vector| vector.len()).sum();
+        let mut params = Vec::with_capacity(len);
+
+        for vector in vectors {
+            params.extend_from_slice(vector.list())
+        }
+
+        Vector::from(params)
+    }
+}
@@ -0,0 +1,312 @@
+use std::cmp::Ordering;
+use crate::vector_impl::Vector;
+use crate::traits::{Ring, Real};
+
+impl<T> Vector<T>
+where
+    T: Real
+{
+    /// Computes the Euclidean (L2) norm, `sqrt(Σ xᵢ²)`.
+    /// 
+    /// The elements are scaled by the largest magnitude before squaring,
+    /// so the result does not overflow or underflow unless the norm itself does.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![3.0, -4.0].norm(), 5.0);
+    /// 
+    /// // Squaring these directly would overflow.
+    /// let norm = vector![3e200_f64, 4e200].norm();
+    /// 
+    /// assert!((norm / 5e200 - 1.0).abs() < 1e-15);
+    /// ```
+    pub fn norm(&self) -> T {
+        let scale = self.norm_inf();
+
+        if scale.is_zero() || scale == T::from_f64(f64::INFINITY) {
+            return scale
+        }
+
+        let mut sum = T::zero();
+
+        for val in self.iter() {
+            let ratio = *val / scale;
+            sum += ratio * ratio
+        }
+
+        scale * sum.sqrt()
+    }
+
+    /// Computes the taxicab (L1) norm, `Σ |xᵢ|`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![3.0, -4.0].norm_l1(), 7.0);
+    /// ```
+    pub fn norm_l1(&self) -> T {
+        let mut sum = T::zero();
+
+        for val in self.iter() {
+            sum += val.abs()
+        }
+
+        sum
+    }
+
+    /// Computes the maximum (L∞) norm, `max |xᵢ|`.
+    /// 
+    /// The norm of an empty Vector is zero, and the norm of a Vector
+    /// containing NaN is NaN.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![3.0, -4.0].norm_inf(), 4.0);
+    /// ```
+    pub fn norm_inf(&self) -> T {
+        let mut max_abs = T::zero();
+
+        for val in self.iter() {
+            match val.abs().partial_cmp(&max_abs) {
+                Some(Ordering::Greater) => max_abs = val.abs(),
+                None => return val.abs(),
+                _ => {}
+            }
+        }
+
+        max_abs
+    }
+
+    /// Computes the p-norm, `(Σ |xᵢ|ᵖ)^(1/p)`.
+    /// 
+    /// Like [norm][Vector::norm], the elements are scaled by the largest
+    /// magnitude first. An infinite `p` gives the [maximum norm][Vector::norm_inf].
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let vector = vector![3.0_f64, -4.0];
+    /// 
+    /// assert_eq!(vector.norm_p(1.0), vector.norm_l1());
+    /// assert!((vector.norm_p(2.0) - vector.norm()).abs() < 1e-12);
+    /// assert_eq!(vector.norm_p(f64::INFINITY), vector.norm_inf());
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if `p` is less than 1 or NaN,
+    /// since the result would not be a norm.
+    pub fn norm_p(&self, p: T) -> T {
+        if !matches!(p.partial_cmp(&T::one()), Some(Ordering::Greater | Ordering::Equal)) {
+            panic!("The p-norm is only defined for p greater than or equal to 1.")
+        }
+
+        let scale = self.norm_inf();
+
+        if scale.is_zero() || scale == T::from_f64(f64::INFINITY) || p == T::from_f64(f64::INFINITY) {
+            return scale
+        }
+
+        let mut sum = T::zero();
+
+        for val in self.iter() {
+            sum += (val.abs() / scale).powf(p)
+        }
+
+        scale * sum.powf(T::one() / p)
+    }
+
+    /// Returns the unit Vector in the same direction, or [None] if
+    /// the Vector has no length or its length is not finite.
+    /// 
+    /// The elements are scaled by the largest magnitude first,
+    /// so a Vector whose [norm][Vector::norm] overflows still has a direction.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![3.0, -4.0].try_normalize(), Some(vector![0.6, -0.8]));
+    /// assert_eq!(vector![0.0, 0.0].try_normalize(), None);
+    /// assert_eq!(vector![f64::NAN, 1.0].try_normalize(), None);
+    /// 
+    /// let unit = vector![f64::MAX, f64::MAX].try_normalize().unwrap();
+    /// 
+    /// assert!((unit[0] - 0.5f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Vector<T>> {
+        let scale = self.norm_inf();
+
+        // Rules out NaN and infinity as well as the zero Vector.
+        if scale.is_zero() || scale.partial_cmp(&T::from_f64(f64::INFINITY)) != Some(Ordering::Less) {
+            return None
+        }
+
+        let scaled = self.lambda(|val| *val / scale);
+        let norm = scaled.norm();
+
+        Some(scaled.lambda(|val| *val / norm))
+    }
+
+    /// Returns the unit Vector in the same direction.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![0.0, 2.0, 0.0].normalize(), vector![0.0, 1.0, 0.0]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the Vector has no length or its length is not finite.
+    /// Use [try_normalize][Vector::try_normalize] to handle that case instead.
+    pub fn normalize(&self) -> Vector<T> {
+        match self.try_normalize() {
+            Some(unit) => unit,
+            None => panic!("Cannot normalize a vector with no length or a length that is not finite.")
+        }
+    }
+
+    /// Computes the angle between two Vectors in radians, from 0 to π.
+    /// 
+    /// The angle between a Vector with no length and any other Vector is zero.
+    /// The angle is NaN if either length is not finite.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// 
+    /// let angle = vector![1.0, 0.0].angle_between(&vector![0.0, 3.0]);
+    /// 
+    /// assert!((angle - FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the vectors are not the same size.
+    pub fn angle_between(&self, other: &Vector<T>) -> T {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        let (lhs, rhs) = match (self.try_normalize(), other.try_normalize()) {
+            (Some(lhs), Some(rhs)) => (lhs, rhs),
+            _ if self.norm_inf().is_zero() || other.norm_inf().is_zero() => return T::zero(),
+            _ => return T::from_f64(f64::NAN)
+        };
+
+        // Unlike taking the arccosine of the dot product, this stays
+        // accurate for nearly parallel and nearly opposite vectors.
+        let two = T::one() + T::one();
+
+        two * (&lhs - &rhs).norm().atan2((&lhs + &rhs).norm())
+    }
+
+    /// Computes the vector projection of `self` onto `other`,
+    /// the component of `self` parallel to `other`.
+    /// 
+    /// Projecting onto a Vector with no length gives the zero Vector,
+    /// and projecting onto a Vector whose length is not finite gives NaN.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let vector = vector![2.0, 3.0];
+    /// let onto = vector![4.0, 0.0];
+    /// 
+    /// assert_eq!(vector.project_onto(&onto), vector![2.0, 0.0]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the vectors are not the same size.
+    pub fn project_onto(&self, other: &Vector<T>) -> Vector<T> {
+        if self.len() != other.len() {
+            panic!("Cannot find dot product of two differently sized vectors.")
+        }
+
+        // Projecting onto the unit vector keeps the products scaled like the norm,
+        // where 'other * other' could overflow or underflow.
+        match other.try_normalize() {
+            Some(unit) => &unit * (self * &unit),
+            None if other.norm_inf().is_zero() => other.lambda(|_| T::zero()),
+            None => other.lambda(|_| T::from_f64(f64::NAN))
+        }
+    }
+
+    /// Computes the vector rejection of `self` from `other`,
+    /// the component of `self` orthogonal to `other`.
+    /// 
+    /// The [projection][Vector::project_onto] and rejection sum to `self`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let vector = vector![2.0, 3.0];
+    /// let from = vector![4.0, 0.0];
+    /// 
+    /// assert_eq!(vector.reject_from(&from), vector![0.0, 3.0]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the vectors are not the same size.
+    pub fn reject_from(&self, other: &Vector<T>) -> Vector<T> {
+        self - &self.project_onto(other)
+    }
+
+    /// Computes the Euclidean distance between two Vectors, `‖self - other‖`.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// assert_eq!(vector![1.0, 1.0].distance(&vector![4.0, 5.0]), 5.0);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if the vectors are not the same size.
+    pub fn distance(&self, other: &Vector<T>) -> T {
+        (self - other).norm()
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Ring
+{
+    /// Computes the cross product of two 3D Vectors,
+    /// which is orthogonal to both of them.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::prelude::*;
+    /// 
+    /// let x = vector![1, 0, 0];
+    /// let y = vector![0, 1, 0];
+    /// 
+    /// assert_eq!(x.cross(&y), vector![0, 0, 1]);
+    /// assert_eq!(y.cross(&x), vector![0, 0, -1]);
+    /// ```
+    /// 
+    /// # Panic!
+    /// This function will panic if either Vector does not have exactly 3 elements.
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        if self.len() != 3 || other.len() != 3 {
+            panic!("The cross product is only defined for vectors of length 3.")
+        }
+
+        let (lhs, rhs) = (self.list(), other.list());
+
+        Vector::from(vec![
+            lhs[1] * rhs[2] - lhs[2] * rhs[1],
+            lhs[2] * rhs[0] - lhs[0] * rhs[2],
+            lhs[0] * rhs[1] - lhs[1] * rhs[0]
+        ])
+    }
+}
@@ -0,0 +1,87 @@
+use std::ops::{Index, IndexMut};
+use crate::vector_impl::Vector;
+
+impl<T> Vector<T> {
+    /// Borrows the element at the given index, or returns [None]
+    /// if the index is out of range.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector = vector![1, 2, 3];
+    /// 
+    /// assert_eq!(vector.get(1), Some(&2));
+    /// assert_eq!(vector.get(3), None);
+    /// ```
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.list.get(idx)
+    }
+
+    /// Mutably borrows the element at the given index, or returns [None]
+    /// if the index is out of range.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let mut vector = vector![1, 2, 3];
+    /// 
+    /// if let Some(val) = vector.get_mut(1) {
+    ///     *val = 5
+    /// }
+    /// 
+    /// assert_eq!(vector, vector![1, 5, 3]);
+    /// assert_eq!(vector.get_mut(3), None);
+    /// ```
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.list.get_mut(idx)
+    }
+}
+
+/// The [indexing][std::ops::Index] implementation for 'Vector\[usize\]'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector = vector![1, 2, 3];
+/// 
+/// assert_eq!(vector[0], 1);
+/// assert_eq!(vector[2], 3);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the index is out of range.
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.list[idx]
+    }
+}
+
+/// The [mutable indexing][std::ops::IndexMut] implementation for 'Vector\[usize\]'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector = vector![1, 2, 3];
+/// 
+/// vector[1] = 7;
+/// 
+/// assert_eq!(vector, vector![1, 7, 3]);
+/// ```
+/// 
+/// # Panic!
+/// This function will panic if the index is out of range.
+impl<T> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.list[idx]
+    }
+}
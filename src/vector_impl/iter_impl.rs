@@ -0,0 +1,128 @@
+use std::iter::FromIterator;
+use crate::vector_impl::Vector;
+
+impl<T> Vector<T> {
+    /// Returns an iterator over the elements of the vector.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector = vector![1, 2, 3];
+    /// 
+    /// let sum: i32 = vector.iter().sum();
+    /// 
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the vector.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let mut vector = vector![1, 2, 3];
+    /// 
+    /// for val in vector.iter_mut() {
+    ///     *val *= 2
+    /// }
+    /// 
+    /// assert_eq!(vector, vector![2, 4, 6]);
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+}
+
+/// Consumes the vector into an iterator over its elements.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector = vector![String::from("a"), String::from("b")];
+/// 
+/// let joined: String = vector.into_iter().collect();
+/// 
+/// assert_eq!(joined, "ab");
+/// ```
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+/// Iterates over borrowed elements of the vector.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector = vector![1, 2, 3];
+/// let mut total = 0;
+/// 
+/// for val in &vector {
+///     total += val
+/// }
+/// 
+/// assert_eq!(total, 6);
+/// ```
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
+
+/// Iterates over mutably borrowed elements of the vector.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let mut vector = vector![1, 2, 3];
+/// 
+/// for val in &mut vector {
+///     *val += 1
+/// }
+/// 
+/// assert_eq!(vector, vector![2, 3, 4]);
+/// ```
+impl<'a, T> IntoIterator for &'a mut Vector<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter_mut()
+    }
+}
+
+/// Collects an iterator into a Vector.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector: Vector<i32> = (1..4).map(|val| val * val).collect();
+/// 
+/// assert_eq!(vector, vector![1, 4, 9]);
+/// ```
+impl<T> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
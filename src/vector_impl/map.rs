@@ -1,4 +1,5 @@
 use crate::vector_impl::Vector;
+use crate::error::{Shape, ShapeError};
 
 impl<T> Vector<T> {
     /// Applies a function dependent on value
@@ -39,7 +40,34 @@ impl<T> Vector<T> {
 
         Vector::from(params)
     }
-    
+
+    /// Applies a function dependent on value to each corresponding
+    /// element between the two vectors, returning a [ShapeError]
+    /// instead of panicking when the vectors are not identically sized.
+    /// 
+    /// # Example
+    /// ```
+    /// use simp_linalg::vector_impl::Vector;
+    /// use simp_linalg::vector;
+    /// 
+    /// let vector_x = vector![1, 2, 3];
+    /// let vector_y = vector![4, 5, 6];
+    /// let vector_z = vector![7, 8];
+    /// 
+    /// assert_eq!(vector_x.try_map(&vector_y, |val1, val2| val1 * val2), Ok(vector![4, 10, 18]));
+    /// assert!(vector_x.try_map(&vector_z, |val1, val2| val1 * val2).is_err());
+    /// ```
+    pub fn try_map<F>(&self, other: &Vector<T>, funct: F) -> Result<Vector<T>, ShapeError>
+    where
+        F: Fn(&T, &T) -> T
+    {
+        if self.len() != other.len() {
+            return Err(ShapeError::new("map", Shape::Vector(self.len()), Shape::Vector(other.len())))
+        }
+
+        Ok(self.map(other, funct))
+    }
+
     /*
         For anyone following the source code, lambda.rs
         has a function called 'lambda_index' while map.rs
@@ -1,7 +1,16 @@
 mod mul_impl;
 mod add_impl;
+mod sub_impl;
+mod neg_impl;
+mod assign_impl;
 mod lambda;
 mod map;
+mod checked;
+mod index_impl;
+mod iter_impl;
+mod constructors;
+mod conjugate;
+mod geometry;
 
 use crate::matrix_impl::Matrix;
 
@@ -46,13 +55,7 @@ impl<T> Vector<T>
     /// ]);
     /// ```
     pub fn into_col_matrix(self) -> Matrix<T> {
-        let mut matrix = Vec::with_capacity(self.len());
-
-        for param in self.list {
-            matrix.push(vec![param])
-        }
-
-        Matrix::from(matrix)
+        Matrix::from_parts(self.len(), 1, self.list)
     }
 
     /// Converts a Vector<T> into a row Matrix<T>, consuming the Vector.
@@ -83,7 +86,7 @@ impl<T> Vector<T>
     /// ]);
     /// ```
     pub fn into_row_matrix(self) -> Matrix<T> {
-        Matrix::from(vec![self.list])
+        Matrix::from_parts(1, self.len(), self.list)
     }
 
     /// Returns the length of the Vector<T>.
@@ -91,6 +94,11 @@ impl<T> Vector<T>
         self.list.len()
     }
 
+    /// Returns true if the Vector<T> has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
     /// Borrows the list of elements in the Vector<T>.
     pub fn list(&self) -> &Vec<T> {
         &self.list
@@ -1,5 +1,6 @@
-use std::ops::{Mul, AddAssign};
+use std::ops::Mul;
 use crate::vector_impl::Vector;
+use crate::traits::Ring;
 
 //
 //
@@ -27,7 +28,7 @@ use crate::vector_impl::Vector;
 /// This function will panic if the vectors are not the same size.
 impl<T> Mul for &Vector<T>
 where
-    T: Copy + Mul<Output = T> + AddAssign + Default
+    T: Ring
 {
     type Output = T;
 
@@ -36,7 +37,7 @@ where
             panic!("Cannot find dot product of two differently sized vectors.")
         }
 
-        let mut product = T::default();
+        let mut product = T::zero();
         
         for idx in 0..self.len() {
             product += self.list[idx] * rhs.list[idx]
@@ -74,7 +75,7 @@ where
 /// This function will panic if the vectors are not the same size.
 impl<T> Mul<Vector<T>> for &Vector<T>
 where
-    T: Copy + Mul<Output = T> + AddAssign + Default
+    T: Ring
 {
     type Output = T;
 
@@ -83,7 +84,7 @@ where
             panic!("Cannot find dot product of two differently sized vectors.")
         }
 
-        let mut product = T::default();
+        let mut product = T::zero();
         
         for idx in 0..self.len() {
             product += self.list[idx] * rhs.list[idx]
@@ -121,7 +122,7 @@ where
 /// This function will panic if the vectors are not the same size.
 impl<T> Mul<&Vector<T>> for Vector<T>
 where
-    T: Copy + Mul<Output = T> + AddAssign + Default
+    T: Ring
 {
     type Output = T;
 
@@ -130,7 +131,7 @@ where
             panic!("Cannot find dot product of two differently sized vectors.")
         }
 
-        let mut product = T::default();
+        let mut product = T::zero();
         
         for idx in 0..self.len() {
             product += self.list[idx] * rhs.list[idx]
@@ -168,7 +169,7 @@ where
 /// This function will panic if the vectors are not the same size.
 impl<T> Mul for Vector<T>
 where
-    T: Copy + Mul<Output = T> + AddAssign + Default
+    T: Ring
 {
     type Output = T;
 
@@ -177,7 +178,7 @@ where
             panic!("Cannot find dot product of two differently sized vectors.")
         }
 
-        let mut product = T::default();
+        let mut product = T::zero();
         
         for idx in 0..self.len() {
             product += self.list[idx] * rhs.list[idx]
@@ -248,11 +249,9 @@ where
 {
     type Output = Vector<T>;
 
-    fn mul(self, rhs: T) -> Self::Output {
-        let mut params = Vec::with_capacity(self.len());
-        for item in self.list() {
-            params.push(rhs * *item)
-        }
-        Vector::from(params)
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+
+        self
     }
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::ops::Neg;
+use crate::vector_impl::Vector;
+
+//
+//
+//          Borrowed Implementation
+//
+//
+/// The [negation][std::ops::Neg] implementation for '-&Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![1, -2, 3];
+/// 
+/// let vector2 = -&vector1;
+/// 
+/// assert_eq!(vector2, vector![-1, 2, -3])
+/// ```
+impl<T> Neg for &Vector<T>
+where
+    T: Neg<Output = T> + Copy
+{
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        let mut params = Vec::with_capacity(self.len());
+        for item in self.list() {
+            params.push(-*item)
+        }
+        Vector::from(params)
+    }
+}
+
+
+//
+//
+//          Owned Implementation
+//
+//
+/// The [negation][std::ops::Neg] implementation for '-Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![1, -2, 3];
+/// 
+/// // Notice that 'vector1' is moved here
+/// let vector2 = -vector1;
+/// 
+/// assert_eq!(vector2, vector![-1, 2, -3])
+/// ```
+/// This is useful for negating the result of another operation.
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![1, 2, 3];
+/// let vector2 = vector![4, 5, 6];
+/// 
+/// let vector3 = -(&vector1 + &vector2);
+/// 
+/// assert_eq!(vector3, vector![-5, -7, -9])
+/// ```
+impl<T> Neg for Vector<T>
+where
+    T: Neg<Output = T>
+{
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        let mut params = Vec::with_capacity(self.len());
+        for item in self.list {
+            params.push(-item)
+        }
+        Vector::from(params)
+    }
+}
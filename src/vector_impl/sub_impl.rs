@@ -0,0 +1,210 @@
+use std::ops::Sub;
+use crate::vector_impl::Vector;
+
+//
+//
+//          Borrowed/Borrowed Implementation
+//
+//
+/// The [subtraction][std::ops::Sub] implementation for '&Vector - &Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// let vector3 = &vector1 - &vector2;
+/// 
+/// assert_eq!(vector3, vector![3, 3, 3])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> Sub for &Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be subtracted from each other.")
+        }
+        
+        let length = self.len();
+
+        let mut params = Vec::with_capacity(length);
+        for idx in 0..length {
+            params.push(self.list[idx] - rhs.list[idx])
+        }
+
+        Vector::from(params)
+    }
+}
+
+
+//
+//
+//          Borrowed/Owned Implementation
+//
+//
+/// The [subtraction][std::ops::Sub] implementation for '&Vector - Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // Notice that 'vector2' is moved here
+/// let vector3 = &vector1 - vector2;
+/// 
+/// assert_eq!(vector3, vector![3, 3, 3]);
+/// ```
+/// This is useful for subtraction of vectors that are scaled.
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // The result of '&vector2 * 2' is an owned Vector,
+/// // which is then subtracted from '&vector1'.
+/// let vector3 = &vector1 - &vector2 * 2;
+/// 
+/// assert_eq!(vector3, vector![2, 1, 0])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> Sub<Vector<T>> for &Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Vector<T>;
+
+    fn sub(self, mut rhs: Vector<T>) -> Self::Output {
+        if self.len() != rhs.len() {
+            panic!("Vectors with different sizes cannot be subtracted from each other.")
+        }
+
+        // The right hand side is owned, so its buffer is reused for the result.
+        for (lhs_val, rhs_val) in self.list.iter().zip(rhs.list.iter_mut()) {
+            *rhs_val = *lhs_val - *rhs_val
+        }
+
+        rhs
+    }
+}
+
+
+//
+//
+//          Owned/Borrowed Implementation
+//
+//
+/// The [subtraction][std::ops::Sub] implementation for 'Vector - &Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // Notice that 'vector1' is moved here
+/// let vector3 = vector1 - &vector2;
+/// 
+/// assert_eq!(vector3, vector![3, 3, 3]);
+/// ```
+/// This is useful for subtraction of vectors that are scaled.
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // The result of '&vector1 * 2' is an owned Vector,
+/// // which '&vector2' is then subtracted from.
+/// let vector3 = &vector1 * 2 - &vector2;
+/// 
+/// assert_eq!(vector3, vector![7, 8, 9])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> Sub<&Vector<T>> for Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Vector<T>;
+
+    fn sub(mut self, rhs: &Vector<T>) -> Self::Output {
+        self -= rhs;
+
+        self
+    }
+}
+
+
+//
+//
+//          Owned/Owned Implementation
+//
+//
+/// The [subtraction][std::ops::Sub] implementation for 'Vector - Vector'.
+/// 
+/// # Example
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // Notice that both vectors are moved here
+/// let vector3 = vector1 - vector2;
+/// 
+/// assert_eq!(vector3, vector![3, 3, 3]);
+/// ```
+/// This is useful for subtraction of vectors that are scaled.
+/// ```
+/// use simp_linalg::vector_impl::Vector;
+/// use simp_linalg::vector;
+/// 
+/// let vector1 = vector![4, 5, 6];
+/// let vector2 = vector![1, 2, 3];
+/// 
+/// // The result of '&vector1 * 2' is an owned Vector,
+/// // which another owned vector '&vector2 * 3' is subtracted from.
+/// let vector3 = &vector1 * 2 - &vector2 * 3;
+/// 
+/// assert_eq!(vector3, vector![5, 4, 3])
+/// ```
+/// 
+/// # Panic!
+/// 
+/// This function will panic if the vectors are not the same size.
+impl<T> Sub for Vector<T>
+where
+    T: Sub<Output = T> + Copy
+{
+    type Output = Vector<T>;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+
+        self
+    }
+}
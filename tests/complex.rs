@@ -0,0 +1,100 @@
+use simp_linalg::prelude::*;
+use simp_linalg::complex::Complex;
+use simp_linalg::traits::ComplexField;
+
+fn sample(rows: usize, cols: usize, seed: usize) -> Matrix<Complex<f64>> {
+    Matrix::from_fn(rows, cols, |row, col| {
+        let phase = (row * 37 + col * 101 + row * col * 13 + seed * 7) as f64;
+
+        Complex::new(phase.sin(), phase.cos() * 0.5)
+    })
+}
+
+fn assert_close(lhs: Complex<f64>, rhs: Complex<f64>) {
+    assert!((lhs - rhs).modulus() < 1e-12, "{:?} != {:?}", lhs, rhs)
+}
+
+#[test]
+fn adjoint_reverses_products() {
+    let lhs = sample(3, 4, 1);
+    let rhs = sample(4, 2, 2);
+
+    let product = (&lhs * &rhs).adjoint();
+    let reversed = &rhs.adjoint() * &lhs.adjoint();
+
+    for (lhs_val, rhs_val) in product.iter().zip(reversed.iter()) {
+        assert_close(*lhs_val, *rhs_val)
+    }
+
+    assert_eq!(lhs.adjoint().adjoint(), lhs);
+    assert_eq!(lhs.conjugate_transpose(), lhs.conjugate().transpose());
+}
+
+#[test]
+fn adjoint_moves_across_the_inner_product() {
+    let matrix = sample(3, 4, 3);
+    let x = sample(4, 1, 4).into_vector();
+    let y = sample(3, 1, 5).into_vector();
+
+    // ⟨A x, y⟩ = ⟨x, Aᴴ y⟩
+    assert_close((&matrix * &x).inner(&y), x.inner(&(&matrix.adjoint() * &y)));
+
+    // ⟨x, y⟩ = conj(⟨y, x⟩), and ⟨x, x⟩ is real and positive.
+    let x = sample(5, 1, 6).into_vector();
+    let y = sample(5, 1, 7).into_vector();
+
+    assert_close(x.inner(&y), y.inner(&x).conjugate());
+    assert!(x.inner(&x).im.abs() < 1e-12 && x.inner(&x).re > 0.0);
+    assert_close(x.inner(&y), &x.conjugate() * &y);
+}
+
+#[test]
+fn products_with_adjoint_are_hermitian() {
+    let matrix = sample(4, 3, 8);
+
+    assert!((&matrix * &matrix.adjoint()).is_hermitian());
+    assert!((&matrix.adjoint() * &matrix).is_hermitian());
+    assert!(!matrix.is_hermitian());
+    assert!(!(&matrix * &matrix.transpose()).is_hermitian());
+}
+
+#[test]
+fn real_matrices_reduce_to_transpose_and_dot() {
+    let matrix: Matrix<f64> = matrix![[1.0, 2.0],
+                                      [3.0, 4.0]];
+    let vector = vector![1.0, -2.0];
+
+    assert_eq!(matrix.adjoint(), matrix.transpose());
+    assert_eq!(vector.inner(&vector), &vector * &vector);
+    assert!((&matrix + &matrix.transpose()).is_hermitian());
+    assert!(!matrix.is_hermitian());
+}
+
+#[test]
+fn division_avoids_overflow_and_underflow() {
+    let large = Complex::new(1e300, 1e300);
+    let small = Complex::new(1e-300, 1e-300);
+
+    assert_eq!(large / large, Complex::new(1.0, 0.0));
+    assert_eq!(small / small.conjugate(), Complex::new(0.0, 1.0));
+    assert_eq!(Complex::new(4.0, 2.0) / Complex::new(0.0, 2.0), Complex::new(1.0, -2.0));
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn num_complex_elements() {
+    use num_complex::Complex64;
+
+    let matrix = matrix![[Complex64::new(2.0, 0.0), Complex64::new(1.0, -1.0)],
+                         [Complex64::new(1.0, 1.0), Complex64::new(3.0, 0.0)]];
+    let vector = vector![Complex64::new(0.0, 1.0), Complex64::new(1.0, 1.0)];
+
+    assert!(matrix.is_hermitian());
+    assert_eq!(matrix.adjoint(), matrix);
+    assert_eq!(vector.inner(&vector), Complex64::new(3.0, 0.0));
+    assert_eq!(Complex64::new(3.0, 4.0).modulus(), 5.0);
+
+    let converted: Complex<f64> = Complex64::new(1.0, 2.0).into();
+    assert_eq!(converted, Complex::new(1.0, 2.0));
+    assert_eq!(Complex64::from(converted), Complex64::new(1.0, 2.0));
+}
@@ -0,0 +1,380 @@
+use simp_linalg::prelude::*;
+use simp_linalg::error::LinalgError;
+use simp_linalg::complex::Complex;
+
+fn assert_close(lhs: &Matrix<f64>, rhs: &Matrix<f64>) {
+    assert_eq!((lhs.rows(), lhs.cols()), (rhs.rows(), rhs.cols()));
+
+    for (lhs_val, rhs_val) in lhs.iter().zip(rhs.iter()) {
+        assert!((lhs_val - rhs_val).abs() < 1e-9, "{:?} != {:?}", lhs, rhs)
+    }
+}
+
+fn sample() -> Matrix<f64> {
+    matrix![[ 4.0, -2.0,  1.0,  3.0,  0.5],
+            [ 1.0,  5.0, -1.0,  2.0,  1.0],
+            [-3.0,  2.0,  6.0, -1.0,  2.0],
+            [ 2.0,  0.0,  1.0,  7.0, -2.0],
+            [ 0.0,  1.0, -2.0,  1.0,  3.0]]
+}
+
+#[test]
+fn lu_reconstructs_and_solves() {
+    let matrix = sample();
+    let lu = matrix.lu().unwrap();
+
+    assert_close(&(lu.p() * &matrix), &(lu.l() * lu.u()));
+
+    let expected = vector![1.0, -2.0, 3.0, 0.5, -1.0];
+    let rhs = &matrix * &expected;
+    let solution = lu.solve(&rhs).unwrap();
+
+    assert_close(&solution.into_col_matrix(), &expected.into_col_matrix());
+
+    let inverse = lu.solve_matrix(&Matrix::identity(5)).unwrap();
+    assert_close(&(&matrix * &inverse), &Matrix::identity(5));
+}
+
+#[test]
+fn lu_reports_singular_and_shape_errors() {
+    let singular: Matrix<f64> = matrix![[1.0, 2.0, 3.0],
+                                        [4.0, 5.0, 6.0],
+                                        [7.0, 8.0, 9.0]];
+    let lu = singular.lu().unwrap();
+
+    assert!(lu.is_singular());
+    assert!(lu.determinant().abs() < 1e-9);
+    assert_eq!(lu.solve(&vector![1.0, 2.0, 3.0]), Err(LinalgError::Singular));
+
+    assert!(matches!(sample().lu().unwrap().solve(&vector![1.0]), Err(LinalgError::Shape(_))));
+    assert_eq!(matrix![[1.0, 2.0]].lu().unwrap_err(), LinalgError::NotSquare(1, 2));
+}
+
+#[test]
+fn inverse_and_pseudo_inverse_agree_on_square_matrices() {
+    let matrix = sample();
+    let inverse = matrix.inverse().unwrap();
+
+    assert_close(&(&inverse * &matrix), &Matrix::identity(5));
+    assert_close(&matrix.pseudo_inverse(1e-12).unwrap(), &inverse);
+}
+
+#[test]
+fn pseudo_inverse_satisfies_penrose_conditions() {
+    let tall = matrix![[1.0, 2.0, 3.0],
+                       [4.0, 5.0, 6.0],
+                       [7.0, 8.0, 9.0],
+                       [1.0, 0.0, 1.0]];
+    let wide = tall.transpose();
+    let rank_deficient = matrix![[1.0, 2.0, 3.0],
+                                 [2.0, 4.0, 6.0]];
+
+    for matrix in [tall, wide, rank_deficient] {
+        let pseudo_inverse = matrix.pseudo_inverse(1e-10).unwrap();
+
+        assert_close(&(&matrix * &pseudo_inverse * &matrix), &matrix);
+        assert_close(&(&pseudo_inverse * &matrix * &pseudo_inverse), &pseudo_inverse);
+        assert_close(&(&matrix * &pseudo_inverse).transpose(), &(&matrix * &pseudo_inverse));
+        assert_close(&(&pseudo_inverse * &matrix).transpose(), &(&pseudo_inverse * &matrix));
+    }
+}
+
+#[test]
+fn qr_reconstructs_in_full_and_thin_forms() {
+    let tall = matrix![[ 2.0, -1.0,  0.0],
+                       [ 1.0,  3.0, -2.0],
+                       [ 0.0,  1.0,  4.0],
+                       [-1.0,  2.0,  1.0],
+                       [ 3.0,  0.0,  2.0]];
+
+    for matrix in [tall.clone(), tall.transpose(), sample()] {
+        for qr in [matrix.qr(), matrix.qr_pivoted()] {
+            let q = qr.q();
+
+            assert_close(&(q.transpose() * &q), &Matrix::identity(matrix.rows()));
+            assert_close(&(&q * qr.r()), &(&matrix * qr.p()));
+            assert_close(&(qr.thin_q() * qr.thin_r()), &(&matrix * qr.p()));
+        }
+    }
+}
+
+#[test]
+fn qr_least_squares_matches_normal_equations() {
+    let matrix = matrix![[1.0, 1.0],
+                         [1.0, 2.0],
+                         [1.0, 3.0],
+                         [1.0, 4.0]];
+    let rhs = vector![6.0, 5.0, 7.0, 10.0];
+
+    let normal = (matrix.transpose() * &matrix).lu().unwrap()
+        .solve(&(matrix.transpose() * &rhs)).unwrap();
+
+    for qr in [matrix.qr(), matrix.qr_pivoted()] {
+        let solution = qr.solve_least_squares(&rhs).unwrap();
+
+        assert_close(&solution.into_col_matrix(), &normal.clone().into_col_matrix());
+    }
+}
+
+#[test]
+fn qr_least_squares_rejects_dependent_columns() {
+    let matrix = matrix![[1.0, 2.0],
+                         [2.0, 4.0],
+                         [3.0, 6.0]];
+
+    assert_eq!(matrix.qr_pivoted().rank(1e-10), 1);
+    assert_eq!(matrix.qr_pivoted().solve_least_squares(&vector![1.0, 2.0, 3.0]), Err(LinalgError::Singular));
+}
+
+#[test]
+fn cholesky_and_ldl_reconstruct_and_solve() {
+    let matrix = sample().transpose() * sample();
+    let expected = vector![1.0, -2.0, 3.0, 0.5, -1.0];
+    let rhs = &matrix * &expected;
+
+    let cholesky = matrix.cholesky().unwrap();
+    let l = cholesky.l();
+
+    assert_close(&(&l * l.transpose()), &matrix);
+    assert_close(&cholesky.solve(&rhs).unwrap().into_col_matrix(), &expected.clone().into_col_matrix());
+    assert_close(&(&matrix * cholesky.inverse()), &Matrix::identity(5));
+
+    let determinant = matrix.lu().unwrap().determinant();
+    assert!((cholesky.determinant() - determinant).abs() < 1e-6 * determinant);
+    assert!((cholesky.log_determinant() - determinant.ln()).abs() < 1e-9);
+
+    let ldl = matrix.ldl().unwrap();
+    let l = ldl.l();
+    let d = Matrix::from_diagonal(&ldl.d());
+
+    assert_close(&(&l * d * l.transpose()), &matrix);
+    assert_close(&ldl.solve(&rhs).unwrap().into_col_matrix(), &expected.into_col_matrix());
+}
+
+#[test]
+fn cholesky_rejects_invalid_matrices() {
+    let indefinite = matrix![[1.0, 2.0],
+                             [2.0, 1.0]];
+    let semi_definite = matrix![[1.0, 2.0],
+                                [2.0, 4.0]];
+    let asymmetric = matrix![[2.0, 1.0],
+                             [0.0, 2.0]];
+
+    assert_eq!(indefinite.cholesky().unwrap_err(), LinalgError::NotPositiveDefinite);
+    assert_eq!(indefinite.ldl().unwrap_err(), LinalgError::NotPositiveDefinite);
+    assert_eq!(semi_definite.cholesky().unwrap_err(), LinalgError::NotPositiveDefinite);
+    assert_eq!(asymmetric.cholesky().unwrap_err(), LinalgError::NotSymmetric);
+    assert_eq!(Matrix::<f64>::zeros(2, 3).ldl().unwrap_err(), LinalgError::NotSquare(2, 3));
+
+    let ldl = semi_definite.ldl().unwrap();
+
+    assert!(ldl.is_singular());
+    assert_eq!(ldl.determinant(), 0.0);
+    assert_eq!(ldl.solve(&vector![1.0, 2.0]), Err(LinalgError::Singular));
+}
+
+#[test]
+fn svd_reconstructs_in_thin_and_full_forms() {
+    let tall = matrix![[ 2.0, -1.0,  0.0],
+                       [ 1.0,  3.0, -2.0],
+                       [ 0.0,  1.0,  4.0],
+                       [-1.0,  2.0,  1.0],
+                       [ 3.0,  0.0,  2.0]];
+    let deficient = matrix![[1.0, 2.0, 3.0],
+                            [2.0, 4.0, 6.0],
+                            [1.0, 0.0, 1.0],
+                            [0.0, 2.0, 2.0]];
+
+    for matrix in [tall.clone(), tall.transpose(), deficient.clone(), deficient.transpose(), sample()] {
+        let rows = matrix.rows();
+        let cols = matrix.cols();
+
+        for svd in [matrix.svd().unwrap(), matrix.full_svd().unwrap()] {
+            let u = svd.u();
+            let vt = svd.vt();
+
+            assert_close(&(u.transpose() * u), &Matrix::identity(u.cols()));
+            assert_close(&(vt * vt.transpose()), &Matrix::identity(vt.rows()));
+
+            let sigma = Matrix::from_fn(u.cols(), vt.rows(), |row, col| {
+                if row == col { svd.singular_values()[row] } else { 0.0 }
+            });
+
+            assert_close(&(u * sigma * vt), &matrix);
+            assert_close(&svd.singular_values().clone().into_col_matrix(), &matrix.singular_values().unwrap().into_col_matrix());
+        }
+
+        let full = matrix.full_svd().unwrap();
+        assert_eq!((full.u().rows(), full.u().cols()), (rows, rows));
+        assert_eq!((full.vt().rows(), full.vt().cols()), (cols, cols));
+    }
+}
+
+#[test]
+fn svd_derived_quantities() {
+    let deficient: Matrix<f64> = matrix![[1.0, 2.0, 3.0],
+                                         [2.0, 4.0, 6.0],
+                                         [1.0, 0.0, 1.0],
+                                         [0.0, 2.0, 2.0]];
+    let svd = deficient.svd().unwrap();
+
+    assert_eq!(svd.rank(1e-10), 2);
+    assert!(svd.condition_number() > 1e12);
+
+    let null_space = svd.null_space();
+    assert_eq!((null_space.rows(), null_space.cols()), (3, 1));
+    assert_close(&(&deficient * &null_space), &Matrix::zeros(4, 1));
+
+    assert_close(&svd.low_rank_approx(2), &deficient);
+    assert_close(&svd.low_rank_approx(0), &Matrix::zeros(4, 3));
+
+    // The rank-one approximation misses by exactly the second singular value.
+    let residual = &deficient - svd.low_rank_approx(1);
+    assert!((residual.singular_values().unwrap()[0] - svd.singular_values()[1]).abs() < 1e-9);
+
+    let wide = deficient.transpose();
+    assert_eq!(wide.svd().unwrap().null_space().cols(), 2);
+    assert_close(&(&wide * wide.svd().unwrap().null_space()), &Matrix::zeros(3, 2));
+
+    let zero: Matrix<f64> = Matrix::zeros(2, 2);
+    assert_eq!(zero.svd().unwrap().condition_number(), f64::INFINITY);
+}
+
+#[test]
+fn svd_reports_no_convergence() {
+    let matrix = matrix![[f64::NAN, 1.0],
+                         [1.0,      2.0]];
+
+    assert_eq!(matrix.svd().unwrap_err(), LinalgError::NoConvergence);
+    assert_eq!(matrix.full_svd().unwrap_err(), LinalgError::NoConvergence);
+    assert_eq!(matrix.singular_values(), Err(LinalgError::NoConvergence));
+    assert_eq!(matrix.pseudo_inverse(1e-12), Err(LinalgError::NoConvergence));
+}
+
+#[test]
+fn symmetric_eigen_reconstructs_with_sorted_oriented_vectors() {
+    let matrix = sample() + sample().transpose();
+    let eigen = matrix.symmetric_eigen().unwrap();
+    let values = eigen.eigenvalues();
+    let vectors = eigen.eigenvectors();
+
+    assert_close(&(vectors.transpose() * vectors), &Matrix::identity(5));
+    assert_close(&(vectors * Matrix::from_diagonal(values) * vectors.transpose()), &matrix);
+
+    for (lhs, rhs) in values.iter().zip(values.iter().skip(1)) {
+        assert!(lhs <= rhs)
+    }
+
+    for col in vectors.col_iter() {
+        let largest = col.copied().fold(0.0f64, |acc, val| if val.abs() > acc.abs() { val } else { acc });
+        assert!(largest > 0.0)
+    }
+
+    let trace: f64 = (0..5).map(|idx| matrix[(idx, idx)]).sum();
+    assert!((values.iter().sum::<f64>() - trace).abs() < 1e-9);
+}
+
+#[test]
+fn symmetric_eigen_rejects_asymmetric_matrices() {
+    assert_eq!(sample().symmetric_eigen().unwrap_err(), LinalgError::NotSymmetric);
+    assert_eq!(Matrix::<f64>::zeros(2, 3).symmetric_eigen().unwrap_err(), LinalgError::NotSquare(2, 3));
+}
+
+#[test]
+fn symmetric_eigen_reports_no_convergence() {
+    let matrix = matrix![[f64::NAN, 1.0],
+                         [1.0,      2.0]];
+
+    assert_eq!(matrix.symmetric_eigen().unwrap_err(), LinalgError::NoConvergence);
+}
+
+fn pseudo_random(rows: usize, cols: usize, seed: u64) -> Matrix<f64> {
+    let mut state = seed;
+
+    Matrix::from_fn(rows, cols, |_, _| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    })
+}
+
+fn assert_eigenpairs(matrix: &Matrix<f64>) {
+    let size = matrix.rows();
+    let eigen = matrix.eigen().unwrap();
+    let values = eigen.eigenvalues();
+    let vectors = eigen.eigenvectors();
+
+    let scale = matrix.iter().fold(1.0f64, |acc, val| acc.max(val.abs())) * size as f64;
+
+    for (idx, value) in values.iter().enumerate() {
+        let vector = vectors.col(idx);
+        let length: f64 = vector.iter().map(|val| val.norm() * val.norm()).sum();
+        assert!((length - 1.0).abs() < 1e-9);
+
+        for row in 0..size {
+            let mut product = Complex::new(0.0, 0.0);
+            for col in 0..size {
+                product += Complex::from(matrix[(row, col)]) * vector[col]
+            }
+
+            assert!((product - *value * vector[row]).norm() < 1e-9 * scale, "{:?}", value)
+        }
+    }
+
+    let trace: f64 = (0..size).map(|idx| matrix[(idx, idx)]).sum();
+    let sum = values.iter().fold(Complex::new(0.0, 0.0), |acc, val| acc + *val);
+    assert!((sum.re - trace).abs() < 1e-9 * scale && sum.im.abs() < 1e-9 * scale);
+
+    assert_eq!(&matrix.eigenvalues().unwrap(), values);
+}
+
+#[test]
+fn eigen_satisfies_eigenpairs_for_general_matrices() {
+    for (size, seed) in [(1, 1), (2, 2), (3, 3), (5, 4), (8, 5), (13, 6), (30, 7)] {
+        assert_eigenpairs(&pseudo_random(size, size, seed))
+    }
+
+    assert_eigenpairs(&sample());
+}
+
+#[test]
+fn eigen_handles_structured_matrices() {
+    // The companion matrix of (x - 1)(x - 2)(x² + 1).
+    let companion = matrix![[3.0, -3.0, 3.0, -2.0],
+                            [1.0,  0.0, 0.0,  0.0],
+                            [0.0,  1.0, 0.0,  0.0],
+                            [0.0,  0.0, 1.0,  0.0]];
+    let mut roots: Vec<(f64, f64)> = companion.eigenvalues().unwrap().iter().map(|val| (val.re, val.im)).collect();
+    roots.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+
+    for (root, expected) in roots.iter().zip([(0.0, -1.0), (0.0, 1.0), (1.0, 0.0), (2.0, 0.0)]) {
+        assert!((root.0 - expected.0).abs() < 1e-9 && (root.1 - expected.1).abs() < 1e-9, "{:?}", roots)
+    }
+    assert_eigenpairs(&companion);
+
+    // A cyclic permutation has all the cube roots of unity as eigenvalues.
+    let cycle: Matrix<f64> = matrix![[0.0, 0.0, 1.0],
+                                    [1.0, 0.0, 0.0],
+                                    [0.0, 1.0, 0.0]];
+    for value in cycle.eigenvalues().unwrap().iter() {
+        assert!((value.norm() - 1.0).abs() < 1e-12)
+    }
+    assert_eigenpairs(&cycle);
+
+    // Repeated and zero eigenvalues.
+    assert_eigenpairs(&matrix![[2.0, 1.0, 0.0],
+                               [0.0, 2.0, 0.0],
+                               [0.0, 0.0, 0.0]]);
+    assert_eigenpairs(&Matrix::identity(4));
+    assert_eigenpairs(&Matrix::zeros(3, 3));
+
+    // Symmetric matrices agree with the symmetric solver.
+    let symmetric = sample() + sample().transpose();
+    let mut values: Vec<f64> = symmetric.eigenvalues().unwrap().iter().map(|val| val.re).collect();
+    values.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+
+    for (lhs, rhs) in values.iter().zip(symmetric.symmetric_eigen().unwrap().eigenvalues().iter()) {
+        assert!((lhs - rhs).abs() < 1e-9)
+    }
+
+    assert_eq!(Matrix::<f64>::zeros(2, 3).eigenvalues().unwrap_err(), LinalgError::NotSquare(2, 3));
+}
@@ -0,0 +1,161 @@
+use simp_linalg::prelude::*;
+
+fn assert_close(lhs: f64, rhs: f64) {
+    assert!((lhs - rhs).abs() <= 1e-12 * rhs.abs().max(1.0), "{lhs} != {rhs}")
+}
+
+fn assert_close_vector(lhs: &Vector<f64>, rhs: &Vector<f64>) {
+    assert_eq!(lhs.len(), rhs.len());
+
+    for (lhs, rhs) in lhs.iter().zip(rhs.iter()) {
+        assert_close(*lhs, *rhs)
+    }
+}
+
+fn sample(len: usize, seed: usize) -> Vector<f64> {
+    Vector::from((0..len).map(|idx| ((idx * 37 + seed * 11) as f64).sin()).collect::<Vec<_>>())
+}
+
+#[test]
+fn norms() {
+    let vector = vector![1.0, -2.0, 2.0];
+
+    assert_eq!(vector.norm(), 3.0);
+    assert_eq!(vector.norm_l1(), 5.0);
+    assert_eq!(vector.norm_inf(), 2.0);
+    assert_close(vector.norm_p(3.0), 17.0_f64.cbrt());
+
+    let empty: Vector<f64> = vector![];
+
+    assert_eq!(empty.norm(), 0.0);
+    assert_eq!(empty.norm_p(2.0), 0.0);
+}
+
+#[test]
+fn norms_avoid_overflow_and_underflow() {
+    assert_close(vector![3e-200, 4e-200].norm() / 1e-200, 5.0);
+    assert_close(vector![1e300, 1e300].norm_p(4.0) / 1e300, 2.0_f64.powf(0.25));
+}
+
+#[test]
+fn projections_avoid_overflow_and_underflow() {
+    let large = vector![3e200, 4e200];
+    let onto = vector![1e200, 0.0];
+
+    assert_close_vector(&(&large.project_onto(&onto) * 1e-200), &vector![3.0, 0.0]);
+    assert_close_vector(&(&large.reject_from(&onto) * 1e-200), &vector![0.0, 4.0]);
+
+    let small = vector![3e-200, 4e-200];
+    let onto = vector![1e-200, 0.0];
+
+    assert_close_vector(&(&small.project_onto(&onto) * 1e200), &vector![3.0, 0.0]);
+    assert_close_vector(&(&small.reject_from(&onto) * 1e200), &vector![0.0, 4.0]);
+    assert_close(small.distance(&onto) / 1e-200, 20.0_f64.sqrt());
+}
+
+#[test]
+fn non_finite_elements_propagate() {
+    let with_nan = vector![f64::NAN, 1.0];
+
+    assert!(with_nan.norm_inf().is_nan());
+    assert!(with_nan.norm().is_nan());
+    assert!(with_nan.norm_l1().is_nan());
+
+    let with_inf = vector![f64::INFINITY, 1.0];
+
+    assert_eq!(with_inf.norm_inf(), f64::INFINITY);
+    assert_eq!(with_inf.norm(), f64::INFINITY);
+    assert_eq!(with_inf.norm_p(3.0), f64::INFINITY);
+}
+
+#[test]
+fn norm_p_matches_other_norms() {
+    let vector = sample(7, 1);
+
+    assert_close(vector.norm_p(1.0), vector.norm_l1());
+    assert_close(vector.norm_p(2.0), vector.norm());
+    assert_eq!(vector.norm_p(f64::INFINITY), vector.norm_inf());
+}
+
+#[test]
+#[should_panic]
+fn norm_p_below_one() {
+    vector![1.0, 2.0].norm_p(0.5);
+}
+
+#[test]
+fn normalize() {
+    let unit = sample(5, 2).normalize();
+
+    assert_close(unit.norm(), 1.0);
+    assert_eq!(vector![0.0_f64, 0.0].try_normalize(), None);
+    assert_eq!(vector![f64::NAN, 1.0].try_normalize(), None);
+    assert_eq!(vector![f64::INFINITY, 1.0].try_normalize(), None);
+
+    let root_half = 0.5_f64.sqrt();
+    for vector in [vector![1e308, 1e308], vector![f64::MAX, f64::MAX], vector![1e-320, 1e-320]] {
+        let unit = vector.try_normalize().unwrap();
+
+        assert_close(unit[0], root_half);
+        assert_close(unit[1], root_half);
+    }
+}
+
+#[test]
+#[should_panic]
+fn normalize_zero() {
+    vector![0.0_f64, 0.0].normalize();
+}
+
+#[test]
+fn cross_is_orthogonal() {
+    let lhs = sample(3, 3);
+    let rhs = sample(3, 4);
+    let cross = lhs.cross(&rhs);
+
+    assert_close(&cross * &lhs, 0.0);
+    assert_close(&cross * &rhs, 0.0);
+    assert_close_vector(&rhs.cross(&lhs), &-cross);
+}
+
+#[test]
+#[should_panic]
+fn cross_wrong_length() {
+    vector![1, 2].cross(&vector![3, 4]);
+}
+
+#[test]
+fn angle_between() {
+    let vector = vector![1.0, 1.0];
+
+    assert_close(vector.angle_between(&vector![1.0, 0.0]), std::f64::consts::FRAC_PI_4);
+    assert_close(vector.angle_between(&(&vector * -2.0)), std::f64::consts::PI);
+    assert_eq!(vector.angle_between(&(&vector * 3.0)), 0.0);
+    assert_eq!(vector.angle_between(&vector![0.0, 0.0]), 0.0);
+    assert!(vector.angle_between(&vector![f64::NAN, 0.0]).is_nan());
+}
+
+#[test]
+fn projection_and_rejection() {
+    let vector = sample(4, 5);
+    let onto = sample(4, 6);
+
+    let projection = vector.project_onto(&onto);
+    let rejection = vector.reject_from(&onto);
+
+    assert_close(&rejection * &onto, 0.0);
+    assert_close_vector(&(&projection + &rejection), &vector);
+    assert_close(projection.angle_between(&onto).min(projection.angle_between(&-onto.clone())), 0.0);
+
+    assert_eq!(vector.project_onto(&vector![0.0; 4]), vector![0.0; 4]);
+    assert!(vector.project_onto(&vector![f64::INFINITY, 0.0, 0.0, 0.0]).iter().all(|val| val.is_nan()));
+}
+
+#[test]
+fn distance() {
+    let lhs = sample(6, 7);
+    let rhs = sample(6, 8);
+
+    assert_close(lhs.distance(&rhs), (&lhs - &rhs).norm());
+    assert_eq!(lhs.distance(&lhs), 0.0);
+}
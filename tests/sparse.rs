@@ -0,0 +1,143 @@
+use simp_linalg::prelude::*;
+use simp_linalg::sparse::{CooMatrix, CsrMatrix, CscMatrix};
+
+/// A deterministic matrix with roughly one nonzero entry in three.
+fn sample(rows: usize, cols: usize, seed: i64) -> Matrix<i64> {
+    Matrix::from_fn(rows, cols, |row, col| {
+        let hash = (row as i64 * 31 + col as i64 * 17 + seed) % 7;
+
+        if hash < 3 { hash - 1 + seed } else { 0 }
+    })
+}
+
+/// The 1D finite-difference Laplacian, assembled entry by entry.
+fn laplacian(size: usize) -> CooMatrix<i64> {
+    let mut coo = CooMatrix::with_capacity(size, size, 3 * size);
+
+    for idx in 0..size {
+        coo.push(idx, idx, 2);
+
+        if idx > 0 {
+            coo.push(idx, idx - 1, -1)
+        }
+        if idx + 1 < size {
+            coo.push(idx, idx + 1, -1)
+        }
+    }
+
+    coo
+}
+
+#[test]
+fn formats_round_trip_through_dense() {
+    for (rows, cols, seed) in [(1, 1, 0), (4, 6, 1), (7, 3, 2), (5, 5, 3), (0, 3, 4)] {
+        let matrix = sample(rows, cols, seed);
+        let coo = CooMatrix::from(&matrix);
+        let csr = CsrMatrix::from(&matrix);
+        let csc = CscMatrix::from(&matrix);
+
+        assert_eq!(Matrix::from(&coo), matrix);
+        assert_eq!(Matrix::from(&csr), matrix);
+        assert_eq!(Matrix::from(&csc), matrix);
+
+        assert_eq!(coo.to_csr(), csr);
+        assert_eq!(coo.to_csc(), csc);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+
+        assert_eq!(Matrix::from(&csr.transpose()), matrix.transpose());
+        assert_eq!(Matrix::from(&csc.transpose()), matrix.transpose());
+        assert_eq!(coo.transpose().to_csr(), csr.transpose());
+    }
+}
+
+#[test]
+fn duplicates_are_summed_in_any_order() {
+    let mut coo = CooMatrix::new(2, 3);
+
+    coo.push(1, 2, 4);
+    coo.push(0, 1, 1);
+    coo.push(1, 0, 2);
+    coo.push(1, 2, -1);
+    coo.push(0, 1, 5);
+
+    let expected = matrix![[0, 6, 0],
+                           [2, 0, 3]];
+
+    assert_eq!(coo.nnz(), 5);
+    assert_eq!(coo.to_csr().nnz(), 3);
+    assert_eq!(coo.to_csr().col_indices(), &[1, 0, 2]);
+    assert_eq!(Matrix::from(&coo.to_csr()), expected);
+    assert_eq!(Matrix::from(&coo.to_csc()), expected);
+    assert_eq!(Matrix::from(&coo), expected);
+}
+
+#[test]
+fn products_match_dense() {
+    let matrix = sample(6, 4, 5);
+    let dense = sample(4, 3, 6);
+    let vector = Vector::from_fn(4, |idx| idx as i64 - 2);
+
+    let csr = CsrMatrix::from(&matrix);
+    let csc = CscMatrix::from(&matrix);
+
+    assert_eq!(&csr * &vector, &matrix * &vector);
+    assert_eq!(&csc * &vector, &matrix * &vector);
+    assert_eq!(&csr * &dense, &matrix * &dense);
+    assert_eq!(&csc * &dense, &matrix * &dense);
+}
+
+#[test]
+fn sums_match_dense() {
+    let lhs = sample(5, 4, 7);
+    let rhs = sample(5, 4, 8);
+    let negated = lhs.lambda(|val| -val);
+
+    let sum = &CsrMatrix::from(&lhs) + &CsrMatrix::from(&rhs);
+    assert_eq!(Matrix::from(&sum), &lhs + &rhs);
+
+    let sum = &CscMatrix::from(&lhs) + &CscMatrix::from(&rhs);
+    assert_eq!(Matrix::from(&sum), &lhs + &rhs);
+
+    // Cancelled entries stay stored as explicit zeros.
+    let sum = &CsrMatrix::from(&lhs) + &CsrMatrix::from(&negated);
+    assert_eq!(sum.nnz(), CsrMatrix::from(&lhs).nnz());
+    assert_eq!(Matrix::from(&sum), Matrix::zeros(5, 4));
+}
+
+#[test]
+fn large_laplacian_stays_sparse() {
+    let size = 100_000;
+    let csr = laplacian(size).to_csr();
+    let ones = Vector::ones(size);
+
+    assert_eq!(csr.nnz(), 3 * size - 2);
+    assert_eq!(csr.get(5, 4), Some(&-1));
+    assert_eq!(csr.get(5, 7), None);
+
+    // Only the boundary rows see a nonzero row sum.
+    let product = &csr * &ones;
+    let product = product.list();
+
+    assert_eq!((product[0], product[size - 1]), (1, 1));
+    assert!(product[1..size - 1].iter().all(|val| *val == 0));
+
+    assert_eq!(csr.transpose(), csr);
+    assert_eq!(&csr.to_csc() * &ones, Vector::from(product.clone()));
+}
+
+#[test]
+#[should_panic]
+fn push_out_of_range_panics() {
+    let mut coo = CooMatrix::new(2, 2);
+
+    coo.push(2, 0, 1);
+}
+
+#[test]
+#[should_panic]
+fn mismatched_product_panics() {
+    let csr = CsrMatrix::from(&sample(3, 4, 0));
+
+    let _ = &csr * &Vector::<i64>::zeros(3);
+}
@@ -0,0 +1,68 @@
+use simp_linalg::prelude::*;
+use simp_linalg::error::Shape;
+
+fn sample(rows: usize, cols: usize, offset: i64) -> Matrix<i64> {
+    Matrix::from_fn(rows, cols, |row, col| (row * cols + col) as i64 + offset)
+}
+
+#[test]
+fn stacks_split_back_into_their_views() {
+    let left = sample(3, 2, 0);
+    let right = sample(3, 4, 10);
+    let bottom = sample(2, 6, 20);
+
+    let joined = Matrix::hstack(&[&left, &right]).unwrap();
+    let stacked = Matrix::vstack(&[&joined, &bottom]).unwrap();
+
+    assert_eq!((stacked.rows(), stacked.cols()), (5, 6));
+    assert_eq!(stacked.view(0..3, 0..2).to_matrix(), left);
+    assert_eq!(stacked.view(0..3, 2..6).to_matrix(), right);
+    assert_eq!(stacked.view(3..5, ..).to_matrix(), bottom);
+}
+
+#[test]
+fn block_matches_nested_stacks() {
+    let a = sample(2, 2, 0);
+    let b = sample(2, 3, 10);
+    let c = sample(1, 4, 20);
+    let d = sample(1, 1, 30);
+
+    let expected = Matrix::vstack(&[
+        &Matrix::hstack(&[&a, &b]).unwrap(),
+        &Matrix::hstack(&[&c, &d]).unwrap()
+    ]).unwrap();
+
+    assert_eq!(Matrix::block(vec![vec![&a, &b], vec![&c, &d]]), Ok(expected));
+}
+
+#[test]
+fn mismatched_shapes_are_reported() {
+    let a = sample(2, 2, 0);
+    let b = sample(3, 2, 0);
+    let c = sample(2, 3, 0);
+
+    let error = Matrix::hstack(&[&a, &a, &b]).unwrap_err();
+    assert_eq!((error.lhs(), error.rhs()), (Shape::Matrix(2, 2), Shape::Matrix(3, 2)));
+
+    let error = Matrix::vstack(&[&a, &c]).unwrap_err();
+    assert_eq!((error.lhs(), error.rhs()), (Shape::Matrix(2, 2), Shape::Matrix(2, 3)));
+
+    let error = Matrix::block(vec![vec![&a, &b]]).unwrap_err();
+    assert_eq!((error.lhs(), error.rhs()), (Shape::Matrix(2, 2), Shape::Matrix(3, 2)));
+
+    // Each block row is fine, but their widths differ.
+    let error = Matrix::block(vec![vec![&a, &a], vec![&c]]).unwrap_err();
+    assert_eq!((error.lhs(), error.rhs()), (Shape::Matrix(2, 4), Shape::Matrix(2, 3)));
+}
+
+#[test]
+fn empty_inputs() {
+    let empty: Matrix<i64> = Matrix::hstack(&[]).unwrap();
+    assert_eq!((empty.rows(), empty.cols()), (0, 0));
+
+    let empty: Matrix<i64> = Matrix::block(vec![]).unwrap();
+    assert_eq!((empty.rows(), empty.cols()), (0, 0));
+
+    let vector: Vector<i64> = Vector::concat(&[]);
+    assert!(vector.is_empty());
+}
@@ -0,0 +1,40 @@
+use simp_linalg::prelude::*;
+
+#[test]
+fn static_arithmetic_matches_dynamic() {
+    let lhs: SMatrix<i64, 3, 4> = SMatrix::from_fn(|row, col| (row * 4 + col) as i64 - 5);
+    let rhs: SMatrix<i64, 4, 2> = SMatrix::from_fn(|row, col| (row as i64 - col as i64) * 3);
+    let vector: SVector<i64, 4> = SVector::from([2, -1, 0, 3]);
+
+    let dynamic_lhs = Matrix::from(lhs);
+    let dynamic_rhs = Matrix::from(rhs);
+    let dynamic_vector = Vector::from(vector);
+
+    assert_eq!(Matrix::from(lhs * rhs), &dynamic_lhs * &dynamic_rhs);
+    assert_eq!(Vector::from(lhs * vector), &dynamic_lhs * &dynamic_vector);
+    assert_eq!(Matrix::from(lhs + lhs), &dynamic_lhs + &dynamic_lhs);
+    assert_eq!(Matrix::from(lhs - lhs * 2), -&dynamic_lhs);
+    assert_eq!(Matrix::from(lhs.transpose()), dynamic_lhs.transpose());
+    assert_eq!(vector * vector, &dynamic_vector * &dynamic_vector);
+
+    let identity: SMatrix<i64, 4, 4> = SMatrix::identity();
+    assert_eq!(lhs * identity, lhs);
+}
+
+#[test]
+fn static_conversions_round_trip_and_check_shapes() {
+    let matrix = matrix![[1.0, 2.0, 3.0],
+                         [4.0, 5.0, 6.0]];
+
+    let fixed: SMatrix<f64, 2, 3> = matrix.clone().try_into().unwrap();
+    assert_eq!(fixed.as_array(), &[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(Matrix::from(fixed), matrix);
+
+    assert!(SMatrix::<f64, 3, 2>::try_from(matrix).is_err());
+
+    let vector = vector![1, 2, 3];
+    let fixed: SVector<i32, 3> = vector.clone().try_into().unwrap();
+    assert_eq!(Vector::from(fixed), vector);
+
+    assert!(SVector::<i32, 4>::try_from(vector).is_err());
+}
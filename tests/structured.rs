@@ -0,0 +1,238 @@
+use simp_linalg::prelude::*;
+use simp_linalg::error::LinalgError;
+use simp_linalg::structured::{Banded, Tridiagonal, Diagonal};
+
+fn assert_close(lhs: &Vector<f64>, rhs: &Vector<f64>) {
+    assert_eq!(lhs.len(), rhs.len());
+
+    for (lhs_val, rhs_val) in lhs.list().iter().zip(rhs.list()) {
+        assert!((lhs_val - rhs_val).abs() < 1e-9 * rhs_val.abs().max(1.0), "{:?} != {:?}", lhs, rhs)
+    }
+}
+
+/// Deterministic values in `[-1, 1]`, with no diagonal dominance.
+fn pseudo_random(row: usize, col: usize, seed: usize) -> f64 {
+    ((row * 37 + col * 101 + row * col * 13 + seed * 7) as f64).sin()
+}
+
+#[test]
+fn banded_matches_dense() {
+    for (size, lower, upper) in [(1, 0, 0), (5, 1, 1), (6, 2, 1), (7, 0, 3), (8, 3, 0), (4, 5, 5)] {
+        let banded = Banded::from_fn(size, lower, upper, |row, col| pseudo_random(row, col, size));
+        let dense = Matrix::from(&banded);
+        let expected = Vector::from_fn(size, |idx| idx as f64 - 2.0);
+
+        for row in 0..size {
+            for col in 0..size {
+                let in_band = col + lower >= row && col <= row + upper;
+
+                assert_eq!(banded.get(row, col).is_some(), in_band);
+
+                if !in_band {
+                    assert_eq!(dense[(row, col)], 0.0)
+                }
+            }
+        }
+
+        let rhs = &dense * &expected;
+
+        assert_close(&(&banded * &expected), &rhs);
+        assert_close(&banded.solve(&rhs).unwrap(), &dense.lu().unwrap().solve(&rhs).unwrap());
+        assert_close(&banded.solve(&rhs).unwrap(), &expected);
+    }
+}
+
+#[test]
+fn tridiagonal_matches_dense() {
+    let size = 9;
+    let tridiagonal = Tridiagonal::new(
+        Vector::from_fn(size - 1, |idx| pseudo_random(idx + 1, idx, 1)),
+        Vector::from_fn(size, |idx| pseudo_random(idx, idx, 2)),
+        Vector::from_fn(size - 1, |idx| pseudo_random(idx, idx + 1, 3))
+    );
+    let dense = Matrix::from(&tridiagonal);
+    let expected = Vector::from_fn(size, |idx| 1.0 / (idx as f64 + 1.0));
+    let rhs = &dense * &expected;
+
+    assert_eq!(Matrix::from(&Banded::from(&tridiagonal)), dense);
+    assert_close(&(&tridiagonal * &expected), &rhs);
+    assert_close(&tridiagonal.solve(&rhs).unwrap(), &expected);
+}
+
+#[test]
+fn large_tridiagonal_solve() {
+    // The implicit heat equation step (I + r * L) * u = u_prev.
+    let size = 200_000;
+    let r = 0.5;
+    let tridiagonal = Tridiagonal::new(
+        Vector::from(vec![-r; size - 1]),
+        Vector::from(vec![1.0 + 2.0 * r; size]),
+        Vector::from(vec![-r; size - 1])
+    );
+    let expected = Vector::from_fn(size, |idx| (idx as f64 * 1e-3).sin());
+    let rhs = &tridiagonal * &expected;
+
+    assert_close(&tridiagonal.solve(&rhs).unwrap(), &expected);
+}
+
+#[test]
+fn reports_singular_and_shape_errors() {
+    // The first two rows are equal.
+    let singular = Banded::from_fn(3, 1, 1, |row, col| if row.max(col) < 2 || row == col { 1.0 } else { 0.0 });
+    assert_eq!(singular.solve(&vector![1.0, 2.0, 3.0]), Err(LinalgError::Singular));
+
+    let zero = Tridiagonal::new(vector![0.0], vector![0.0, 0.0], vector![0.0]);
+    assert_eq!(zero.solve(&vector![1.0, 1.0]), Err(LinalgError::Singular));
+
+    assert!(matches!(singular.solve(&vector![1.0]), Err(LinalgError::Shape(_))));
+}
+
+#[test]
+#[should_panic]
+fn mismatched_diagonals_panic() {
+    Tridiagonal::new(vector![1.0, 1.0], vector![1.0, 1.0], vector![1.0]);
+}
+
+fn sample() -> Matrix<f64> {
+    Matrix::from_fn(5, 5, |row, col| pseudo_random(row, col, 11) + if row == col { 3.0 } else { 0.0 })
+}
+
+fn assert_close_matrix(lhs: &Matrix<f64>, rhs: &Matrix<f64>) {
+    assert_eq!((lhs.rows(), lhs.cols()), (rhs.rows(), rhs.cols()));
+
+    for (lhs_val, rhs_val) in lhs.iter().zip(rhs.iter()) {
+        assert!((lhs_val - rhs_val).abs() < 1e-9, "{:?} != {:?}", lhs, rhs)
+    }
+}
+
+#[test]
+fn triangles_split_the_matrix() {
+    let matrix = sample();
+    let upper = matrix.upper().unwrap();
+    let lower = matrix.lower().unwrap();
+    let diagonal = Matrix::from_fn(5, 5, |row, col| if row == col { matrix[(row, col)] } else { 0.0 });
+
+    assert_eq!(upper.as_matrix() + lower.as_matrix(), &matrix + &diagonal);
+    assert_eq!(upper.transpose(), matrix.transpose().lower().unwrap());
+    assert_eq!(lower.transpose(), matrix.transpose().upper().unwrap());
+    assert_eq!(matrix![[1.0, 2.0]].upper().unwrap_err(), LinalgError::NotSquare(1, 2));
+}
+
+#[test]
+fn triangular_operations_match_dense() {
+    let matrix = sample();
+    let vector = Vector::from_fn(5, |idx| idx as f64 - 1.5);
+    let rhs = Matrix::from_fn(5, 3, |row, col| pseudo_random(row, col, 12));
+
+    let upper = matrix.upper().unwrap();
+    let lower = matrix.lower().unwrap();
+
+    assert!((upper.determinant() - upper.as_matrix().lu().unwrap().determinant()).abs() < 1e-9);
+    assert!((lower.determinant() - lower.as_matrix().lu().unwrap().determinant()).abs() < 1e-9);
+
+    assert_close(&(&upper * &vector), &(upper.as_matrix() * &vector));
+    assert_close(&(&lower * &vector), &(lower.as_matrix() * &vector));
+    assert_close_matrix(&(&upper * &rhs), &(upper.as_matrix() * &rhs));
+    assert_close_matrix(&(&lower * &rhs), &(lower.as_matrix() * &rhs));
+
+    assert_close(&upper.solve(&(&upper * &vector)).unwrap(), &vector);
+    assert_close(&lower.solve(&(&lower * &vector)).unwrap(), &vector);
+    assert_close_matrix(&upper.solve_matrix(&(&upper * &rhs)).unwrap(), &rhs);
+    assert_close_matrix(&lower.solve_matrix(&(&lower * &rhs)).unwrap(), &rhs);
+
+    let upper_inverse = upper.inverse().unwrap();
+    let lower_inverse = lower.inverse().unwrap();
+
+    assert_close_matrix(&(&upper_inverse * upper.as_matrix()), &Matrix::identity(5));
+    assert_close_matrix(&(&lower_inverse * lower.as_matrix()), &Matrix::identity(5));
+    assert_eq!(upper_inverse.as_matrix().upper().unwrap(), upper_inverse);
+    assert_eq!(lower_inverse.as_matrix().lower().unwrap(), lower_inverse);
+}
+
+#[test]
+fn triangular_reports_singular_and_shape_errors() {
+    let upper = matrix![[1.0, 2.0],
+                        [0.0, 0.0]].upper().unwrap();
+
+    assert_eq!(upper.determinant(), 0.0);
+    assert_eq!(upper.solve(&vector![1.0, 1.0]), Err(LinalgError::Singular));
+    assert_eq!(upper.inverse(), Err(LinalgError::Singular));
+
+    let lower = Matrix::<f64>::identity(2).lower().unwrap();
+
+    assert!(matches!(lower.solve(&vector![1.0]), Err(LinalgError::Shape(_))));
+    assert!(matches!(lower.solve_matrix(&Matrix::identity(3)), Err(LinalgError::Shape(_))));
+}
+
+#[test]
+fn diagonal_matches_dense() {
+    let diagonal = Diagonal::new(vector![2.0, -0.5, 4.0, 1.0, 0.25]);
+    let dense = Matrix::from(&diagonal);
+    let matrix = sample();
+    let wide = Matrix::from_fn(5, 3, |row, col| pseudo_random(row, col, 13));
+    let vector = Vector::from_fn(5, |idx| idx as f64 - 1.5);
+
+    assert_eq!(&diagonal * &matrix, &dense * &matrix);
+    assert_eq!(&matrix * &diagonal, &matrix * &dense);
+    assert_eq!(&diagonal * &wide, &dense * &wide);
+    assert_eq!(&wide.transpose() * &diagonal, wide.transpose() * &dense);
+    assert_eq!(&diagonal * &vector, &dense * &vector);
+    assert_eq!(Matrix::from(&(&diagonal * &diagonal)), &dense * &dense);
+
+    assert_eq!(&diagonal + &matrix, &dense + &matrix);
+    assert_eq!(&matrix + &diagonal, &matrix + &dense);
+
+    assert_eq!(diagonal.determinant(), -1.0);
+    assert_eq!(Matrix::from(&diagonal.inverse().unwrap()), dense.inverse().unwrap());
+    assert_eq!(&diagonal * &diagonal.inverse().unwrap(), Diagonal::identity(5));
+}
+
+#[test]
+fn owned_diagonal_operators_match_borrowed() {
+    let diagonal = Diagonal::new(vector![2.0, -0.5, 4.0]);
+    let matrix = Matrix::from_fn(3, 3, |row, col| pseudo_random(row, col, 14));
+    let tall = Matrix::from_fn(3, 2, |row, col| pseudo_random(row, col, 15));
+    let vector = vector![1.0, 2.0, 3.0];
+
+    let scaled_rows = &diagonal * &tall;
+    assert_eq!(diagonal.clone() * &tall, scaled_rows);
+    assert_eq!(&diagonal * tall.clone(), scaled_rows);
+    assert_eq!(diagonal.clone() * tall.clone(), scaled_rows);
+
+    let scaled_cols = &tall.transpose() * &diagonal;
+    assert_eq!(tall.transpose() * &diagonal, scaled_cols);
+    assert_eq!(&tall.transpose() * diagonal.clone(), scaled_cols);
+    assert_eq!(tall.transpose() * diagonal.clone(), scaled_cols);
+
+    let scaled = &diagonal * &vector;
+    assert_eq!(diagonal.clone() * &vector, scaled);
+    assert_eq!(&diagonal * vector.clone(), scaled);
+    assert_eq!(diagonal.clone() * vector.clone(), scaled);
+
+    let squared = &diagonal * &diagonal;
+    assert_eq!(diagonal.clone() * &diagonal, squared);
+    assert_eq!(&diagonal * diagonal.clone(), squared);
+    assert_eq!(diagonal.clone() * diagonal.clone(), squared);
+
+    let shifted = &diagonal + &matrix;
+    assert_eq!(diagonal.clone() + &matrix, shifted);
+    assert_eq!(&diagonal + matrix.clone(), shifted);
+    assert_eq!(diagonal.clone() + matrix.clone(), shifted);
+    assert_eq!(matrix.clone() + &diagonal, shifted);
+    assert_eq!(&matrix + diagonal.clone(), shifted);
+    assert_eq!(matrix.clone() + diagonal.clone(), shifted);
+}
+
+#[test]
+fn singular_diagonal_has_no_inverse() {
+    let diagonal = Diagonal::new(vector![1.0, 0.0]);
+
+    assert_eq!(diagonal.determinant(), 0.0);
+    assert_eq!(diagonal.inverse(), Err(LinalgError::Singular));
+}
+
+#[test]
+#[should_panic]
+fn mismatched_diagonal_sum_panics() {
+    let _ = &Diagonal::new(vector![1.0, 2.0]) + &matrix![[1.0, 2.0]];
+}
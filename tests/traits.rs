@@ -0,0 +1,87 @@
+use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, MulAssign};
+use simp_linalg::prelude::*;
+use simp_linalg::traits::{Zero, One};
+
+// A number whose Default is deliberately not its additive identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Shifted(i32);
+
+impl Default for Shifted {
+    fn default() -> Self {
+        Shifted(100)
+    }
+}
+
+impl Add for Shifted {
+    type Output = Shifted;
+    fn add(self, rhs: Shifted) -> Shifted { Shifted(self.0 + rhs.0) }
+}
+
+impl Sub for Shifted {
+    type Output = Shifted;
+    fn sub(self, rhs: Shifted) -> Shifted { Shifted(self.0 - rhs.0) }
+}
+
+impl Mul for Shifted {
+    type Output = Shifted;
+    fn mul(self, rhs: Shifted) -> Shifted { Shifted(self.0 * rhs.0) }
+}
+
+impl AddAssign for Shifted {
+    fn add_assign(&mut self, rhs: Shifted) { self.0 += rhs.0 }
+}
+
+impl SubAssign for Shifted {
+    fn sub_assign(&mut self, rhs: Shifted) { self.0 -= rhs.0 }
+}
+
+impl MulAssign for Shifted {
+    fn mul_assign(&mut self, rhs: Shifted) { self.0 *= rhs.0 }
+}
+
+impl Zero for Shifted {
+    fn zero() -> Self { Shifted(0) }
+    fn is_zero(&self) -> bool { self.0 == 0 }
+}
+
+impl One for Shifted {
+    fn one() -> Self { Shifted(1) }
+}
+
+// Implementing the num-traits identities alongside the crate's own
+// must keep compiling when the 'num-traits' feature is enabled.
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Shifted {
+    fn zero() -> Self { Shifted(0) }
+    fn is_zero(&self) -> bool { self.0 == 0 }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Shifted {
+    fn one() -> Self { Shifted(1) }
+}
+
+#[test]
+fn products_seed_with_zero_not_default() {
+    let vector1 = vector![Shifted(1), Shifted(2)];
+    let vector2 = vector![Shifted(3), Shifted(4)];
+
+    assert_eq!(&vector1 * &vector2, Shifted(11));
+
+    let matrix = matrix![[Shifted(1), Shifted(0)],
+                         [Shifted(0), Shifted(1)]];
+
+    assert_eq!(&matrix * &vector1, vector1);
+    assert_eq!(&matrix * &matrix, matrix);
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn wrapping_identities_are_bridged() {
+    use std::num::Wrapping;
+
+    let vector = vector![Wrapping(u8::MAX), Wrapping(2)];
+
+    assert_eq!(&vector * &vector, Wrapping(5));
+    assert!(Wrapping(0_u8).is_zero());
+}
@@ -0,0 +1,17 @@
+use simp_linalg::prelude::*;
+
+#[test]
+fn transpose_variants_agree_across_shapes() {
+    for &(rows, cols) in &[(0, 0), (1, 5), (5, 1), (3, 7), (40, 33), (64, 65)] {
+        let matrix = Matrix::from_fn(rows, cols, |row, col| row * 1000 + col);
+        let expected = Matrix::from_fn(cols, rows, |row, col| col * 1000 + row);
+
+        assert_eq!(matrix.transpose(), expected);
+        assert_eq!(matrix.clone().into_transpose(), expected);
+
+        let mut in_place = matrix.clone();
+        in_place.transpose_mut();
+        in_place.transpose_mut();
+        assert_eq!(in_place, matrix);
+    }
+}
@@ -0,0 +1,91 @@
+use simp_linalg::prelude::*;
+
+fn sample() -> Matrix<i64> {
+    Matrix::from_fn(5, 6, |row, col| (row * 6 + col) as i64 - 7)
+}
+
+#[test]
+fn views_match_copied_blocks() {
+    let matrix = sample();
+
+    for rows in [0..5, 1..4, 2..2, 4..5] {
+        for cols in [0..6, 2..5, 3..3, 5..6] {
+            let view = matrix.view(rows.clone(), cols.clone());
+            let expected = Matrix::from_fn(rows.len(), cols.len(), |row, col| matrix[(rows.start + row, cols.start + col)]);
+
+            assert_eq!((view.rows(), view.cols()), (rows.len(), cols.len()));
+            assert_eq!(view.to_matrix(), expected);
+            assert_eq!(view.lambda(|val| val * 2), &expected * 2);
+        }
+    }
+
+    assert_eq!(matrix.row_view(3).to_matrix(), Matrix::from_rows(vec![Vector::from(matrix.row(3).to_vec())]));
+    assert_eq!(matrix.col_view(4).to_matrix(), matrix.col(4).into_col_matrix());
+    assert_eq!(matrix.view(1.., 1..).view(1..3, ..2).to_matrix(), matrix.view(2..4, 1..3).to_matrix());
+}
+
+#[test]
+fn view_operators_match_owned_operators() {
+    let matrix = sample();
+
+    let lhs = matrix.view(..3, 1..5);
+    let rhs = matrix.view(2.., 2..6);
+    let square = matrix.view(1..5, ..4);
+
+    assert_eq!(lhs + rhs, lhs.to_matrix() + rhs.to_matrix());
+    assert_eq!(lhs - rhs, lhs.to_matrix() - rhs.to_matrix());
+    assert_eq!(lhs + &rhs.to_matrix(), lhs.to_matrix() + rhs.to_matrix());
+    assert_eq!(&lhs.to_matrix() - rhs, lhs.to_matrix() - rhs.to_matrix());
+
+    assert_eq!(lhs * square, &lhs.to_matrix() * &square.to_matrix());
+    assert_eq!(lhs * &square.to_matrix(), &lhs.to_matrix() * &square.to_matrix());
+    assert_eq!(&lhs.to_matrix() * square, &lhs.to_matrix() * &square.to_matrix());
+
+    let vector = vector![1, -2, 3, -4];
+    assert_eq!(lhs * &vector, &lhs.to_matrix() * &vector);
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut matrix = sample();
+    let original = matrix.clone();
+
+    {
+        let mut block = matrix.view_mut(1..3, 2..4);
+        block[(0, 0)] = 100;
+        block.row_mut(1)[1] = 200;
+        block.view_mut(.., 1..).iter_mut().for_each(|val| *val += 1);
+    }
+
+    assert_eq!(matrix[(1, 2)], 100);
+    assert_eq!(matrix[(1, 3)], original[(1, 3)] + 1);
+    assert_eq!(matrix[(2, 3)], 201);
+
+    let source = original.view(..2, ..2).to_matrix();
+    matrix.view_mut(3.., 4..).copy_from(source.view(.., ..));
+    assert_eq!(matrix.view(3.., 4..).to_matrix(), source);
+
+    for (idx, val) in matrix.col_view_mut(0).iter_mut().enumerate() {
+        *val = idx as i64
+    }
+    assert_eq!(matrix.col(0), vector![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn views_without_columns_keep_their_rows() {
+    let mut matrix = sample();
+    let view = matrix.view(..3, 2..2);
+
+    assert_eq!(view.row(2), &[] as &[i64]);
+    assert_eq!(view.row_iter().count(), 3);
+    assert_eq!(view * &vector![], vector![0, 0, 0]);
+    assert_eq!(view * matrix.view(..0, ..2), Matrix::zeros(3, 2));
+
+    assert!(matrix.view_mut(1.., 6..).row_mut(3).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn view_out_of_range_panics() {
+    sample().view(2..6, ..);
+}